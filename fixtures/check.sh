@@ -0,0 +1,16 @@
+#!/bin/sh
+# Scores every fixture round and compares it with its `# Expected score:` header.
+# Usage: fixtures/check.sh [path/to/ortalab]
+bin=${1:-target/release/ortalab}
+status=0
+for file in $(find "$(dirname "$0")" -name '*.yml' | sort); do
+    expected=$(sed -n 's/^# Expected score: //p' "$file")
+    actual=$("$bin" "$file" | head -n 1)
+    if [ "$actual" = "$expected" ]; then
+        echo "ok   $file"
+    else
+        echo "FAIL $file: expected $expected, got $actual"
+        status=1
+    fi
+done
+exit $status
@@ -0,0 +1,305 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use crate::model::{Card, DeckVariant, JokerCard, Round, RunState};
+use serde::Deserialize;
+
+use crate::deck::{MAX_SELECTED_CARDS, starting_cards};
+use crate::pokerhand::create_poker_hand;
+use crate::rng::Rng;
+use crate::score::{ScoreError, ScoreManager};
+
+/// Largest hand the advisor evaluates, which keeps the discards to try in the thousands.
+pub const MAX_HAND_SIZE: usize = 16;
+
+/// Describes the in-run situation the advisor recommends a discard for.
+#[derive(Deserialize)]
+pub struct AdvisorInput {
+    pub hand: Vec<Card>,
+
+    /// Cards left to draw from. Defaults to the starting deck minus the hand.
+    #[serde(default)]
+    pub deck: Option<Vec<Card>>,
+
+    #[serde(default)]
+    pub deck_variant: Option<DeckVariant>,
+
+    #[serde(default)]
+    pub jokers: Vec<JokerCard>,
+
+    #[serde(default = "default_discards_left")]
+    pub discards_left: u32,
+
+    #[serde(default = "default_hands_left")]
+    pub hands_left: u32,
+}
+
+fn default_discards_left() -> u32 {
+    3
+}
+
+fn default_hands_left() -> u32 {
+    4
+}
+
+/// Controls how many draws are simulated for each possible discard.
+pub struct AdvisorOptions {
+    pub samples: usize,
+    pub seed: u64,
+}
+
+impl Default for AdvisorOptions {
+    fn default() -> Self {
+        AdvisorOptions {
+            samples: 200,
+            seed: 0,
+        }
+    }
+}
+
+/// Why the advisor could not recommend a discard.
+#[derive(Debug, PartialEq)]
+pub enum AdvisorError {
+    HandTooLarge { cards: usize },
+    Score(ScoreError),
+}
+
+impl fmt::Display for AdvisorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdvisorError::HandTooLarge { cards } => write!(
+                f,
+                "hand of {} cards is larger than the {} the advisor evaluates",
+                cards, MAX_HAND_SIZE
+            ),
+            AdvisorError::Score(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for AdvisorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AdvisorError::Score(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The expected outcome of discarding one particular set of cards.
+pub struct DiscardOption {
+    pub discard: Vec<Card>,
+    pub expected_score: f64,
+    pub hand_probabilities: Vec<(&'static str, f64)>,
+}
+
+/// Every evaluated discard, best expected score first.
+pub struct DiscardAdvice {
+    pub options: Vec<DiscardOption>,
+}
+
+impl fmt::Display for DiscardOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.discard.is_empty() {
+            write!(f, "Keep hand")?;
+        } else {
+            let cards: Vec<String> = self.discard.iter().map(|card| card.to_string()).collect();
+            write!(f, "Discard {}", cards.join(", "))?;
+        }
+        write!(f, " (Expected Score: {:.1})", self.expected_score)?;
+
+        for (hand_name, probability) in &self.hand_probabilities {
+            write!(f, "\n  {}: {:.1}%", hand_name, probability * 100.0)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DiscardAdvice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(best) = self.options.first() else {
+            return write!(f, "No hands left to play");
+        };
+
+        writeln!(f, "Recommended: {}", best)?;
+
+        let alternatives: Vec<String> = self
+            .options
+            .iter()
+            .skip(1)
+            .take(4)
+            .map(|option| option.to_string())
+            .collect();
+        if !alternatives.is_empty() {
+            write!(f, "Alternatives:\n{}", alternatives.join("\n"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Removes one card of matching rank and suit from the deck for every card in the hand.
+fn deck_without_hand(hand: &[Card], variant: Option<DeckVariant>, seed: u64) -> Vec<Card> {
+    let mut deck = starting_cards(variant, seed);
+    for card in hand {
+        if let Some(pos) = deck
+            .iter()
+            .position(|c| c.rank == card.rank && c.suit == card.suit)
+        {
+            deck.remove(pos);
+        }
+    }
+    deck
+}
+
+/// Plays the best poker hand contained in `hand` in the given run and returns its score
+/// and name.
+pub fn best_play(
+    hand: &[Card],
+    jokers: &[JokerCard],
+    run: &RunState,
+) -> Result<(f64, Option<&'static str>), ScoreError> {
+    let poker_hand = create_poker_hand();
+    let Some((evaluator, hand_cards)) = poker_hand.find_best_hand(hand, jokers) else {
+        return Ok((0.0, None));
+    };
+
+    // Compare positions in the hand rather than values, so a played card does not also
+    // remove its duplicates from the cards held.
+    let cards_held_in_hand: Vec<Card> = hand
+        .iter()
+        .filter(|card| !hand_cards.iter().any(|played| std::ptr::eq(*played, *card)))
+        .cloned()
+        .collect();
+    let cards_played: Vec<Card> = hand_cards.into_iter().cloned().collect();
+
+    let round = Round {
+        run: run.clone(),
+        ..Round::new(cards_played, cards_held_in_hand, jokers.to_vec())
+    };
+    let score = ScoreManager::from_round(&round).calculate_score()?;
+    Ok((score.to_f64(), Some(evaluator.name())))
+}
+
+/// Every way to choose at most `max` of `count` indices, fewest first.
+fn selections(count: usize, max: usize) -> Vec<Vec<usize>> {
+    let mut selections = vec![Vec::new()];
+    let mut start = 0;
+    for _ in 0..max {
+        let end = selections.len();
+        for i in start..end {
+            let first = selections[i].last().map_or(0, |last| last + 1);
+            for index in first..count {
+                let mut selection = selections[i].clone();
+                selection.push(index);
+                selections.push(selection);
+            }
+        }
+        start = end;
+    }
+    selections
+}
+
+/// Recommends which cards to discard by simulating the draws that would replace them.
+pub fn advise_discard(
+    input: &AdvisorInput,
+    options: &AdvisorOptions,
+) -> Result<DiscardAdvice, AdvisorError> {
+    if input.hand.len() > MAX_HAND_SIZE {
+        return Err(AdvisorError::HandTooLarge {
+            cards: input.hand.len(),
+        });
+    }
+    if input.hands_left == 0 {
+        return Ok(DiscardAdvice {
+            options: Vec::new(),
+        });
+    }
+
+    let deck = input
+        .deck
+        .clone()
+        .unwrap_or_else(|| deck_without_hand(&input.hand, input.deck_variant, options.seed));
+
+    let max_discard = if input.discards_left == 0 {
+        0
+    } else {
+        MAX_SELECTED_CARDS.min(input.hand.len())
+    };
+
+    let mut results = Vec::new();
+    for selection in selections(input.hand.len(), max_discard) {
+        let mut discard = Vec::new();
+        let mut kept = Vec::new();
+        for (i, card) in input.hand.iter().enumerate() {
+            if selection.contains(&i) {
+                discard.push(*card);
+            } else {
+                kept.push(*card);
+            }
+        }
+
+        results.push(
+            evaluate_discard(discard, &kept, &deck, input, options).map_err(AdvisorError::Score)?,
+        );
+    }
+
+    results.sort_by(|a, b| b.expected_score.total_cmp(&a.expected_score));
+    Ok(DiscardAdvice { options: results })
+}
+
+/// Estimates the next play's score and hand type after discarding and redrawing.
+fn evaluate_discard(
+    discard: Vec<Card>,
+    kept: &[Card],
+    deck: &[Card],
+    input: &AdvisorInput,
+    options: &AdvisorOptions,
+) -> Result<DiscardOption, ScoreError> {
+    let draw_count = discard.len().min(deck.len());
+    let samples = if draw_count == 0 {
+        1
+    } else {
+        options.samples.max(1)
+    };
+
+    // The play uses up one hand, and the discard before it one discard.
+    let run = RunState {
+        hands_left: input.hands_left.saturating_sub(1),
+        discards_left: if discard.is_empty() {
+            input.discards_left
+        } else {
+            input.discards_left - 1
+        },
+        deck_variant: input.deck_variant,
+        ..RunState::default()
+    };
+
+    let mut rng = Rng::new(options.seed);
+    let mut remaining = deck.to_vec();
+    let mut total_score = 0.0;
+    let mut hand_counts: HashMap<&'static str, usize> = HashMap::new();
+
+    for _ in 0..samples {
+        rng.partial_shuffle(&mut remaining, draw_count);
+
+        let mut hand = kept.to_vec();
+        hand.extend_from_slice(&remaining[..draw_count]);
+
+        let (score, hand_name) = best_play(&hand, &input.jokers, &run)?;
+        total_score += score;
+        if let Some(hand_name) = hand_name {
+            *hand_counts.entry(hand_name).or_insert(0) += 1;
+        }
+    }
+
+    let mut hand_probabilities: Vec<(&'static str, f64)> = hand_counts
+        .into_iter()
+        .map(|(name, count)| (name, count as f64 / samples as f64))
+        .collect();
+    hand_probabilities.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+
+    Ok(DiscardOption {
+        discard,
+        expected_score: total_score / samples as f64,
+        hand_probabilities,
+    })
+}
@@ -0,0 +1,220 @@
+use std::fmt;
+
+use crate::bignum::BigNum;
+use crate::model::{Card, JokerCard, Round};
+use crate::ops::{OpSource, ScoreOp, TracedOp};
+use crate::score::{ScoreError, ScoreManager};
+
+/// Everything one source added to a hand, split into Chips, Mult and xMult.
+pub struct SourceTotals {
+    pub source: OpSource,
+    pub chips: BigNum,
+    pub mult: BigNum,
+    pub times_mult: BigNum,
+    pub retriggers: usize,
+    pub money: i64,
+}
+
+impl SourceTotals {
+    fn new(source: OpSource) -> Self {
+        SourceTotals {
+            source,
+            chips: BigNum::zero(),
+            mult: BigNum::zero(),
+            times_mult: BigNum::one(),
+            retriggers: 0,
+            money: 0,
+        }
+    }
+
+    fn add(&mut self, op: &ScoreOp) {
+        match op {
+            ScoreOp::AddChips(value) => self.chips += value,
+            ScoreOp::AddMult(value) => self.mult += value,
+            ScoreOp::MulMult(value) => self.times_mult *= value,
+            ScoreOp::Retrigger => self.retriggers += 1,
+            ScoreOp::EarnMoney(dollars) => self.money += *dollars,
+        }
+    }
+}
+
+impl fmt::Display for SourceTotals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.chips.is_zero() {
+            parts.push(format!("+{} Chips", self.chips));
+        }
+        if !self.mult.is_zero() {
+            parts.push(format!("+{} Mult", self.mult));
+        }
+        if self.times_mult != BigNum::one() {
+            parts.push(format!("x{} Mult", self.times_mult));
+        }
+        if self.retriggers > 0 {
+            parts.push(format!("{} retrigger(s)", self.retriggers));
+        }
+        if self.money != 0 {
+            parts.push(format!("+${}", self.money));
+        }
+        if parts.is_empty() {
+            parts.push("no effect".to_string());
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// A joker or card and the score of the round without it.
+pub struct Marginal {
+    pub label: String,
+    pub score_without: BigNum,
+}
+
+/// How much of a round's score each joker and card is responsible for.
+pub struct AttributionReport {
+    pub score: BigNum,
+    pub hand_name: Option<String>,
+    /// Every operation in the order it was applied.
+    pub trace: Vec<TracedOp>,
+    /// Totals for each source, in the order they first acted.
+    pub totals: Vec<SourceTotals>,
+    /// Jokers in slot order, then played cards, then cards held in hand.
+    pub marginals: Vec<Marginal>,
+}
+
+impl AttributionReport {
+    fn source_label(&self, source: &OpSource) -> String {
+        match source {
+            OpSource::Hand => self
+                .hand_name
+                .as_deref()
+                .unwrap_or("Poker hand")
+                .to_string(),
+            _ => source.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AttributionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Score: {} ({})",
+            self.score,
+            self.hand_name.as_deref().unwrap_or("No hand")
+        )?;
+
+        writeln!(f, "Contributions in order:")?;
+        for traced in &self.trace {
+            writeln!(
+                f,
+                "  {}: {} ({} x {})",
+                self.source_label(&traced.source),
+                traced.op,
+                traced.chips,
+                traced.mult
+            )?;
+        }
+
+        writeln!(f, "Totals by source:")?;
+        for totals in &self.totals {
+            writeln!(f, "  {}: {}", self.source_label(&totals.source), totals)?;
+        }
+
+        write!(f, "Score without each joker and card:")?;
+        for marginal in &self.marginals {
+            let difference = &self.score - &marginal.score_without;
+            let sign = if difference >= BigNum::zero() {
+                "+"
+            } else {
+                ""
+            };
+            write!(
+                f,
+                "\n  {}: {} ({}{})",
+                marginal.label, marginal.score_without, sign, difference
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Scores a round and attributes the score to its jokers and cards.
+pub fn attribute_score(round: &Round) -> Result<AttributionReport, ScoreError> {
+    let mut manager = ScoreManager::from_round(round);
+    let score = manager.calculate_score()?;
+    let trace = manager.trace().to_vec();
+
+    let mut totals: Vec<SourceTotals> = Vec::new();
+    for traced in &trace {
+        let index = match totals.iter().position(|t| t.source == traced.source) {
+            Some(index) => index,
+            None => {
+                totals.push(SourceTotals::new(traced.source));
+                totals.len() - 1
+            }
+        };
+        totals[index].add(&traced.op);
+    }
+
+    let mut marginals = Vec::new();
+    for (index, joker) in round.jokers.iter().enumerate() {
+        let without = Round {
+            jokers: without_index(&round.jokers, index),
+            ..round.clone()
+        };
+        marginals.push(marginal(joker_label(joker), &without)?);
+    }
+    for (index, card) in round.cards_played.iter().enumerate() {
+        let without = Round {
+            cards_played: without_index(&round.cards_played, index),
+            ..round.clone()
+        };
+        marginals.push(marginal(card_label(card, "played"), &without)?);
+    }
+    for (index, card) in round.cards_held_in_hand.iter().enumerate() {
+        let without = Round {
+            cards_held_in_hand: without_index(&round.cards_held_in_hand, index),
+            ..round.clone()
+        };
+        marginals.push(marginal(card_label(card, "held"), &without)?);
+    }
+
+    Ok(AttributionReport {
+        score,
+        hand_name: manager.best_hand_name().map(str::to_string),
+        trace,
+        totals,
+        marginals,
+    })
+}
+
+/// Scores a round missing one joker or card. Removing the only played card leaves
+/// nothing to score, which counts as a score of zero.
+fn marginal(label: String, without: &Round) -> Result<Marginal, ScoreError> {
+    let score_without = match ScoreManager::from_round(without).calculate_score() {
+        Err(ScoreError::NoCardsPlayed) => BigNum::zero(),
+        score => score?,
+    };
+    Ok(Marginal {
+        label,
+        score_without,
+    })
+}
+
+fn joker_label(joker: &JokerCard) -> String {
+    joker.to_string()
+}
+
+fn card_label(card: &Card, place: &str) -> String {
+    format!("{} ({})", card, place)
+}
+
+/// Returns a copy of `items` with one entry removed.
+fn without_index<T: Copy>(items: &[T], index: usize) -> Vec<T> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != index)
+        .map(|(_, item)| *item)
+        .collect()
+}
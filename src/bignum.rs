@@ -0,0 +1,363 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub};
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+/// Running chip total of a scoring round.
+pub type Chips = BigNum;
+
+/// Running mult of a scoring round.
+pub type Mult = BigNum;
+
+/// Scores at or above this are shown in scientific notation by `to_game_string`.
+const GAME_NOTATION_THRESHOLD: u32 = 11;
+
+/// An exact decimal number of unbounded size, stored as `digits / 10^scale`.
+#[derive(Clone, Debug)]
+pub struct BigNum {
+    digits: BigInt,
+    scale: u32,
+}
+
+impl BigNum {
+    pub fn zero() -> Self {
+        BigNum {
+            digits: BigInt::zero(),
+            scale: 0,
+        }
+    }
+
+    pub fn one() -> Self {
+        BigNum {
+            digits: BigInt::from(1),
+            scale: 0,
+        }
+    }
+
+    /// Returns `digits / 10^scale`, so `BigNum::new(15, 1)` is exactly 1.5.
+    pub fn new(digits: i64, scale: u32) -> Self {
+        BigNum {
+            digits: BigInt::from(digits),
+            scale,
+        }
+        .normalized()
+    }
+
+    /// Converts an `f64` to the shortest decimal that round-trips to it, so effect
+    /// constants such as `1.5` or `0.1` are used as the decimal values the game
+    /// defines rather than their binary approximations. NaN and infinities have no
+    /// decimal value and are rejected.
+    pub fn from_f64(value: f64) -> Result<Self, NotFinite> {
+        if !value.is_finite() {
+            return Err(NotFinite(value));
+        }
+
+        // `f64`'s `Display` never uses exponent notation, so this always parses.
+        Ok(value.to_string().parse().unwrap_or_default())
+    }
+
+    /// Strips trailing decimal zeros so equal values share one representation.
+    fn normalized(mut self) -> Self {
+        let ten = BigInt::from(10);
+        while self.scale > 0 {
+            let (quotient, remainder) = self.digits.div_rem(&ten);
+            if !remainder.is_zero() {
+                break;
+            }
+            self.digits = quotient;
+            self.scale -= 1;
+        }
+        if self.digits.is_zero() {
+            self.scale = 0;
+        }
+        self
+    }
+
+    /// Returns the digits rescaled to a larger scale.
+    fn digits_at_scale(&self, scale: u32) -> BigInt {
+        &self.digits * pow10(scale - self.scale)
+    }
+
+    /// Rounds down to the nearest integer.
+    pub fn floor(&self) -> Self {
+        BigNum {
+            digits: self.digits.div_floor(&pow10(self.scale)),
+            scale: 0,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_zero()
+    }
+
+    /// Converts to the nearest `f64`, saturating to infinity past its range.
+    pub fn to_f64(&self) -> f64 {
+        if self.scale == 0 {
+            return self.digits.to_f64().unwrap_or(f64::NAN);
+        }
+        format!("{}e-{}", self.digits, self.scale)
+            .parse()
+            .unwrap_or(f64::NAN)
+    }
+
+    /// Formats the integer part in scientific notation with the given significant digits,
+    /// e.g. `1.23e45`.
+    pub fn to_scientific(&self, significant: usize) -> String {
+        let integer = self.floor().digits;
+        let sign = if integer.is_negative() { "-" } else { "" };
+        let mut text = integer.abs().to_string();
+        let mut exponent = text.len() - 1;
+        let significant = significant.max(1);
+
+        if text.len() > significant {
+            let round_up = text.as_bytes()[significant] >= b'5';
+            let mut leading: BigInt = text[..significant].parse().unwrap_or_default();
+            if round_up {
+                leading += 1;
+            }
+            text = leading.to_string();
+            if text.len() > significant {
+                text.truncate(significant);
+                exponent += 1;
+            }
+        }
+
+        let text = text.trim_end_matches('0');
+        if text.is_empty() {
+            return "0e0".to_string();
+        }
+
+        let (first, rest) = text.split_at(1);
+        if rest.is_empty() {
+            format!("{}{}e{}", sign, first, exponent)
+        } else {
+            format!("{}{}.{}e{}", sign, first, rest, exponent)
+        }
+    }
+
+    /// Rounds the integer part towards zero to the given significant digits, e.g. `123456`
+    /// to `120000` with two.
+    pub fn truncate_to_significant(&self, significant: u32) -> Self {
+        let integer = self.floor().digits;
+        let length = integer.abs().to_string().len() as u32;
+        let precision = pow10(length.saturating_sub(significant.max(1)));
+        BigNum {
+            digits: integer / &precision * precision,
+            scale: 0,
+        }
+    }
+
+    /// Formats like the game's score counter: exact below 10^11, `1.23e45` above.
+    pub fn to_game_string(&self) -> String {
+        let integer = self.floor();
+        if integer.digits.abs() < pow10(GAME_NOTATION_THRESHOLD) {
+            integer.to_string()
+        } else {
+            integer.to_scientific(3)
+        }
+    }
+}
+
+/// Returns `10^exponent`.
+fn pow10(exponent: u32) -> BigInt {
+    num_traits::pow(BigInt::from(10), exponent as usize)
+}
+
+impl Default for BigNum {
+    fn default() -> Self {
+        BigNum::zero()
+    }
+}
+
+/// An `f64` that is NaN or infinite, and so has no exact decimal value.
+#[derive(Debug, PartialEq)]
+pub struct NotFinite(pub f64);
+
+impl fmt::Display for NotFinite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a finite number", self.0)
+    }
+}
+
+impl Error for NotFinite {}
+
+impl From<i64> for BigNum {
+    fn from(value: i64) -> Self {
+        BigNum::new(value, 0)
+    }
+}
+
+impl TryFrom<f64> for BigNum {
+    type Error = NotFinite;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        BigNum::from_f64(value)
+    }
+}
+
+impl FromStr for BigNum {
+    type Err = String;
+
+    /// Parses a decimal such as `-12.034`, optionally with an exponent as in `1.2e20`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => {
+                let exponent: i64 = exponent
+                    .parse()
+                    .map_err(|err| format!("Invalid exponent in `{s}`: {err}"))?;
+                (mantissa, exponent)
+            }
+            None => (s, 0),
+        };
+
+        let (negative, unsigned) = match mantissa.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, mantissa),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(format!("Invalid decimal number: `{s}`"));
+        }
+
+        let mut digits: BigInt = format!("{integer}{fraction}")
+            .parse()
+            .map_err(|err| format!("Invalid decimal number `{s}`: {err}"))?;
+        if negative {
+            digits = -digits;
+        }
+
+        // Move the decimal point by the exponent.
+        let shift = exponent - fraction.len() as i64;
+        let out_of_range = |_| format!("Exponent of `{s}` is out of range");
+        let scale = if shift >= 0 {
+            digits *= pow10(u32::try_from(shift).map_err(out_of_range)?);
+            0
+        } else {
+            u32::try_from(-shift).map_err(out_of_range)?
+        };
+
+        Ok(BigNum { digits, scale }.normalized())
+    }
+}
+
+impl fmt::Display for BigNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.digits);
+        }
+
+        let sign = if self.digits.is_negative() { "-" } else { "" };
+        let magnitude = self.digits.abs().to_string();
+        let scale = self.scale as usize;
+        let padded = format!("{:0>width$}", magnitude, width = scale + 1);
+        let (integer, fraction) = padded.split_at(padded.len() - scale);
+        write!(f, "{}{}.{}", sign, integer, fraction)
+    }
+}
+
+impl PartialEq for BigNum {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigNum {}
+
+impl PartialOrd for BigNum {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigNum {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        self.digits_at_scale(scale)
+            .cmp(&other.digits_at_scale(scale))
+    }
+}
+
+impl Add for &BigNum {
+    type Output = BigNum;
+
+    fn add(self, other: &BigNum) -> BigNum {
+        let scale = self.scale.max(other.scale);
+        BigNum {
+            digits: self.digits_at_scale(scale) + other.digits_at_scale(scale),
+            scale,
+        }
+        .normalized()
+    }
+}
+
+impl Add for BigNum {
+    type Output = BigNum;
+
+    fn add(self, other: BigNum) -> BigNum {
+        &self + &other
+    }
+}
+
+impl Sub for &BigNum {
+    type Output = BigNum;
+
+    fn sub(self, other: &BigNum) -> BigNum {
+        let scale = self.scale.max(other.scale);
+        BigNum {
+            digits: self.digits_at_scale(scale) - other.digits_at_scale(scale),
+            scale,
+        }
+        .normalized()
+    }
+}
+
+impl Sub for BigNum {
+    type Output = BigNum;
+
+    fn sub(self, other: BigNum) -> BigNum {
+        &self - &other
+    }
+}
+
+impl Mul for &BigNum {
+    type Output = BigNum;
+
+    fn mul(self, other: &BigNum) -> BigNum {
+        BigNum {
+            digits: &self.digits * &other.digits,
+            scale: self.scale + other.scale,
+        }
+        .normalized()
+    }
+}
+
+impl Mul for BigNum {
+    type Output = BigNum;
+
+    fn mul(self, other: BigNum) -> BigNum {
+        &self * &other
+    }
+}
+
+impl AddAssign<&BigNum> for BigNum {
+    fn add_assign(&mut self, other: &BigNum) {
+        *self = &*self + other;
+    }
+}
+
+impl MulAssign<&BigNum> for BigNum {
+    fn mul_assign(&mut self, other: &BigNum) {
+        *self = &*self * other;
+    }
+}
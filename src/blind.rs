@@ -0,0 +1,317 @@
+use std::{error::Error, fmt};
+
+use crate::model::{Card, DeckVariant, JokerCard, JokerSlotError, Round, check_joker_slots};
+use serde::Deserialize;
+
+use crate::bignum::{BigNum, NotFinite};
+use crate::deck::{self, DEFAULT_HAND_SIZE, Deck, DeckError};
+use crate::economy::Earnings;
+use crate::joker::Phase;
+use crate::model::{self, RunState};
+use crate::rng::Rng;
+use crate::score::{ScoreError, ScoreManager};
+
+/// One step taken by the player during a blind, selecting cards by their index in hand.
+///
+/// Written in YAML as `- play: [0, 1, 2]` or `- discard: [3, 4]`.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum BlindAction {
+    Play { play: Vec<usize> },
+    Discard { discard: Vec<usize> },
+}
+
+/// A complete blind: its requirement, the resources available and the moves to make.
+#[derive(Deserialize)]
+pub struct BlindInput {
+    pub target: f64,
+
+    #[serde(default = "default_hands")]
+    pub hands: u32,
+
+    #[serde(default = "default_discards")]
+    pub discards: u32,
+
+    #[serde(default = "default_hand_size")]
+    pub hand_size: usize,
+
+    #[serde(default)]
+    pub seed: u64,
+
+    /// Deck composition. Defaults to the starting cards of `deck_variant`.
+    #[serde(default)]
+    pub deck: Option<Vec<Card>>,
+
+    /// Starting deck, which can change the cards, hand size and scoring.
+    #[serde(default)]
+    pub deck_variant: Option<DeckVariant>,
+
+    #[serde(default)]
+    pub jokers: Vec<JokerCard>,
+
+    pub actions: Vec<BlindAction>,
+}
+
+fn default_hands() -> u32 {
+    4
+}
+
+fn default_discards() -> u32 {
+    3
+}
+
+fn default_hand_size() -> usize {
+    DEFAULT_HAND_SIZE
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BlindError {
+    NoHandsLeft { action: usize },
+    NoDiscardsLeft { action: usize },
+    InvalidSelection { action: usize, error: DeckError },
+    TooManyJokers(JokerSlotError),
+    InvalidTarget(NotFinite),
+    Score { action: usize, error: ScoreError },
+}
+
+impl fmt::Display for BlindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlindError::NoHandsLeft { action } => {
+                write!(f, "action {}: no hands left to play", action + 1)
+            }
+            BlindError::NoDiscardsLeft { action } => {
+                write!(f, "action {}: no discards left", action + 1)
+            }
+            BlindError::InvalidSelection { action, error } => {
+                write!(f, "action {}: {}", action + 1, error)
+            }
+            BlindError::TooManyJokers(error) => write!(f, "{}", error),
+            BlindError::InvalidTarget(error) => write!(f, "target: {}", error),
+            BlindError::Score { action, error } => write!(f, "action {}: {}", action + 1, error),
+        }
+    }
+}
+
+impl Error for BlindError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BlindError::Score { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The outcome of one played hand.
+pub struct HandResult {
+    pub cards_played: Vec<Card>,
+    pub hand_name: Option<String>,
+    pub score: BigNum,
+    pub cumulative_score: BigNum,
+}
+
+/// The outcome of a whole blind.
+pub struct BlindResult {
+    pub target: f64,
+    pub hands: Vec<HandResult>,
+    pub discards_used: u32,
+    pub total_score: BigNum,
+    pub beaten: bool,
+    /// Money earned during the blind and at the end of the round.
+    pub earnings: Earnings,
+    /// The jokers after the round, with Perishable stickers counted down.
+    pub jokers: Vec<JokerCard>,
+}
+
+impl fmt::Display for BlindResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, hand) in self.hands.iter().enumerate() {
+            let cards: Vec<String> = hand.cards_played.iter().map(|c| c.to_string()).collect();
+            writeln!(
+                f,
+                "Hand {}: {} [{}] +{} (Total: {} / {})",
+                i + 1,
+                hand.hand_name.as_deref().unwrap_or("No hand"),
+                cards.join(", "),
+                hand.score,
+                hand.cumulative_score,
+                self.target
+            )?;
+        }
+        write!(
+            f,
+            "{} with {} after {} hand(s) and {} discard(s)",
+            if self.beaten {
+                "Blind beaten"
+            } else {
+                "Blind failed"
+            },
+            self.total_score,
+            self.hands.len(),
+            self.discards_used
+        )?;
+        if self.earnings.starting_money != 0 || self.earnings.total() != 0 {
+            write!(f, "\n{}", self.earnings)?;
+        }
+        if let Some(lineup) = model::lineup_after_round(&self.jokers) {
+            write!(f, "\n{}", lineup)?;
+        }
+        Ok(())
+    }
+}
+
+/// Plays out a blind, scoring each hand and stopping once the target is reached.
+///
+/// Chance effects roll from `seed`, with one generator shared by every hand and event.
+pub fn simulate_blind(input: &BlindInput) -> Result<BlindResult, BlindError> {
+    let hand_size_bonus = input
+        .deck_variant
+        .map_or(0, |variant| variant.hand_size_bonus());
+    let mut deck = match &input.deck {
+        Some(cards) => Deck::from_cards(cards.clone()),
+        None => Deck::from_cards(deck::starting_cards(input.deck_variant, input.seed)),
+    }
+    .with_hand_size(input.hand_size + hand_size_bonus);
+    deck.shuffle(input.seed);
+
+    let mut hands = Vec::new();
+    let mut discards_used = 0;
+    let target = BigNum::from_f64(input.target).map_err(BlindError::InvalidTarget)?;
+    let mut total_score = BigNum::zero();
+    let mut run = RunState {
+        seed: input.seed,
+        deck_variant: input.deck_variant,
+        ..RunState::default()
+    };
+    let starting_money = run.money;
+    check_joker_slots(&input.jokers, run.joker_slot_count()).map_err(BlindError::TooManyJokers)?;
+
+    let mut rng = Rng::new(run.seed);
+    run = run_event(
+        Phase::BlindSelected,
+        None,
+        &[],
+        &input.jokers,
+        &run,
+        &mut rng,
+    );
+    deck.draw_to_hand_size();
+
+    for (action_index, action) in input.actions.iter().enumerate() {
+        if total_score >= target {
+            break;
+        }
+
+        match action {
+            BlindAction::Play { play: indices } => {
+                if hands.len() as u32 >= input.hands {
+                    return Err(BlindError::NoHandsLeft {
+                        action: action_index,
+                    });
+                }
+
+                let mut round = deck.play(indices, &input.jokers).map_err(|error| {
+                    BlindError::InvalidSelection {
+                        action: action_index,
+                        error,
+                    }
+                })?;
+                round.run = run.clone();
+                round.run.hands_left = input.hands - hands.len() as u32 - 1;
+                round.run.discards_left = input.discards - discards_used;
+                round.run.deck = Some(deck.full_deck());
+                round.run.cards_in_deck = Some(deck.cards_remaining());
+
+                let mut manager = ScoreManager::from_round(&round).with_rng(rng.clone());
+                let score = manager
+                    .calculate_score()
+                    .map_err(|error| BlindError::Score {
+                        action: action_index,
+                        error,
+                    })?;
+                total_score += &score;
+                run = manager.run_state().clone();
+                rng = manager.rng().clone();
+
+                hands.push(HandResult {
+                    cards_played: round.cards_played,
+                    hand_name: manager.best_hand_name().map(str::to_string),
+                    score,
+                    cumulative_score: total_score.clone(),
+                });
+            }
+            BlindAction::Discard { discard: indices } => {
+                if discards_used >= input.discards {
+                    return Err(BlindError::NoDiscardsLeft {
+                        action: action_index,
+                    });
+                }
+
+                let discarded =
+                    deck.discard(indices)
+                        .map_err(|error| BlindError::InvalidSelection {
+                            action: action_index,
+                            error,
+                        })?;
+                for card in discarded {
+                    run = run_event(
+                        Phase::Discard,
+                        Some(card),
+                        deck.hand(),
+                        &input.jokers,
+                        &run,
+                        &mut rng,
+                    );
+                }
+                discards_used += 1;
+            }
+        }
+
+        deck.draw_to_hand_size();
+    }
+
+    // Hands only carry the money earned while playing; the round is settled once, with
+    // the cards left in hand.
+    let mut end_of_round = ScoreManager::from_round(&Round {
+        run: run.clone(),
+        ..Round::new(Vec::new(), deck.hand().to_vec(), input.jokers.clone())
+    })
+    .with_rng(rng);
+    end_of_round.end_round();
+    let earnings = Earnings {
+        starting_money,
+        during_scoring: run.money - starting_money,
+        ..end_of_round.earnings().clone()
+    };
+
+    Ok(BlindResult {
+        target: input.target,
+        hands,
+        discards_used,
+        beaten: total_score >= target,
+        total_score,
+        earnings,
+        jokers: end_of_round.lineup().to_vec(),
+    })
+}
+
+/// Runs an event that happens between hands through the same joker dispatch as scoring,
+/// returning the run state with the money the jokers earn. Chances are rolled with `rng`,
+/// which carries on from one event to the next.
+fn run_event(
+    phase: Phase,
+    card: Option<Card>,
+    hand: &[Card],
+    jokers: &[JokerCard],
+    run: &RunState,
+    rng: &mut Rng,
+) -> RunState {
+    let mut manager = ScoreManager::from_round(&Round {
+        run: run.clone(),
+        ..Round::new(Vec::new(), hand.to_vec(), jokers.to_vec())
+    })
+    .with_rng(rng.clone());
+    manager.dispatch_event(phase, card);
+    *rng = manager.rng().clone();
+    manager.run_state().clone()
+}
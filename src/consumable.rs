@@ -0,0 +1,277 @@
+use std::{fmt, str::FromStr};
+
+use crate::model::{named, string_serde};
+
+/// The 22 tarot cards.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tarot {
+    TheFool,
+    TheMagician,
+    TheHighPriestess,
+    TheEmpress,
+    TheEmperor,
+    TheHierophant,
+    TheLovers,
+    TheChariot,
+    Justice,
+    TheHermit,
+    TheWheelOfFortune,
+    Strength,
+    TheHangedMan,
+    Death,
+    Temperance,
+    TheDevil,
+    TheTower,
+    TheStar,
+    TheMoon,
+    TheSun,
+    Judgement,
+    TheWorld,
+}
+
+impl Tarot {
+    pub const ALL: &'static [Tarot] = &[
+        Tarot::TheFool,
+        Tarot::TheMagician,
+        Tarot::TheHighPriestess,
+        Tarot::TheEmpress,
+        Tarot::TheEmperor,
+        Tarot::TheHierophant,
+        Tarot::TheLovers,
+        Tarot::TheChariot,
+        Tarot::Justice,
+        Tarot::TheHermit,
+        Tarot::TheWheelOfFortune,
+        Tarot::Strength,
+        Tarot::TheHangedMan,
+        Tarot::Death,
+        Tarot::Temperance,
+        Tarot::TheDevil,
+        Tarot::TheTower,
+        Tarot::TheStar,
+        Tarot::TheMoon,
+        Tarot::TheSun,
+        Tarot::Judgement,
+        Tarot::TheWorld,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tarot::TheFool => "The Fool",
+            Tarot::TheMagician => "The Magician",
+            Tarot::TheHighPriestess => "The High Priestess",
+            Tarot::TheEmpress => "The Empress",
+            Tarot::TheEmperor => "The Emperor",
+            Tarot::TheHierophant => "The Hierophant",
+            Tarot::TheLovers => "The Lovers",
+            Tarot::TheChariot => "The Chariot",
+            Tarot::Justice => "Justice",
+            Tarot::TheHermit => "The Hermit",
+            Tarot::TheWheelOfFortune => "The Wheel of Fortune",
+            Tarot::Strength => "Strength",
+            Tarot::TheHangedMan => "The Hanged Man",
+            Tarot::Death => "Death",
+            Tarot::Temperance => "Temperance",
+            Tarot::TheDevil => "The Devil",
+            Tarot::TheTower => "The Tower",
+            Tarot::TheStar => "The Star",
+            Tarot::TheMoon => "The Moon",
+            Tarot::TheSun => "The Sun",
+            Tarot::Judgement => "Judgement",
+            Tarot::TheWorld => "The World",
+        }
+    }
+}
+
+/// Planet cards, each levelling up one poker hand.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Planet {
+    Pluto,
+    Mercury,
+    Uranus,
+    Venus,
+    Saturn,
+    Jupiter,
+    Earth,
+    Mars,
+    Neptune,
+    PlanetX,
+    Ceres,
+    Eris,
+}
+
+impl Planet {
+    pub const ALL: &'static [Planet] = &[
+        Planet::Pluto,
+        Planet::Mercury,
+        Planet::Uranus,
+        Planet::Venus,
+        Planet::Saturn,
+        Planet::Jupiter,
+        Planet::Earth,
+        Planet::Mars,
+        Planet::Neptune,
+        Planet::PlanetX,
+        Planet::Ceres,
+        Planet::Eris,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Planet::Pluto => "Pluto",
+            Planet::Mercury => "Mercury",
+            Planet::Uranus => "Uranus",
+            Planet::Venus => "Venus",
+            Planet::Saturn => "Saturn",
+            Planet::Jupiter => "Jupiter",
+            Planet::Earth => "Earth",
+            Planet::Mars => "Mars",
+            Planet::Neptune => "Neptune",
+            Planet::PlanetX => "Planet X",
+            Planet::Ceres => "Ceres",
+            Planet::Eris => "Eris",
+        }
+    }
+
+    /// The poker hand this planet levels up.
+    pub fn hand_name(&self) -> &'static str {
+        match self {
+            Planet::Pluto => "High Card",
+            Planet::Mercury => "Pair",
+            Planet::Uranus => "Two Pair",
+            Planet::Venus => "Three Of A Kind",
+            Planet::Saturn => "Straight",
+            Planet::Jupiter => "Flush",
+            Planet::Earth => "Full House",
+            Planet::Mars => "Four of a Kind",
+            Planet::Neptune => "Straight Flush",
+            Planet::PlanetX => "Five of a Kind",
+            Planet::Ceres => "Flush House",
+            Planet::Eris => "Flush Five",
+        }
+    }
+
+    /// Secret planets only appear once their poker hand has been played.
+    pub fn is_secret(&self) -> bool {
+        matches!(self, Planet::PlanetX | Planet::Ceres | Planet::Eris)
+    }
+}
+
+/// Spectral cards, which make bigger changes to cards and jokers than tarots.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spectral {
+    Familiar,
+    Grim,
+    Incantation,
+    Talisman,
+    Aura,
+    Sigil,
+    Ouija,
+    Ectoplasm,
+    Immolate,
+    Ankh,
+    DejaVu,
+    Hex,
+    Trance,
+    Medium,
+    Cryptid,
+}
+
+impl Spectral {
+    pub const ALL: &'static [Spectral] = &[
+        Spectral::Familiar,
+        Spectral::Grim,
+        Spectral::Incantation,
+        Spectral::Talisman,
+        Spectral::Aura,
+        Spectral::Sigil,
+        Spectral::Ouija,
+        Spectral::Ectoplasm,
+        Spectral::Immolate,
+        Spectral::Ankh,
+        Spectral::DejaVu,
+        Spectral::Hex,
+        Spectral::Trance,
+        Spectral::Medium,
+        Spectral::Cryptid,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Spectral::Familiar => "Familiar",
+            Spectral::Grim => "Grim",
+            Spectral::Incantation => "Incantation",
+            Spectral::Talisman => "Talisman",
+            Spectral::Aura => "Aura",
+            Spectral::Sigil => "Sigil",
+            Spectral::Ouija => "Ouija",
+            Spectral::Ectoplasm => "Ectoplasm",
+            Spectral::Immolate => "Immolate",
+            Spectral::Ankh => "Ankh",
+            Spectral::DejaVu => "Deja Vu",
+            Spectral::Hex => "Hex",
+            Spectral::Trance => "Trance",
+            Spectral::Medium => "Medium",
+            Spectral::Cryptid => "Cryptid",
+        }
+    }
+}
+
+named!(Tarot, Planet, Spectral);
+
+/// A card held in a consumable slot.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Consumable {
+    Tarot(Tarot),
+    Planet(Planet),
+    Spectral(Spectral),
+}
+
+impl Consumable {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Consumable::Tarot(tarot) => tarot.name(),
+            Consumable::Planet(planet) => planet.name(),
+            Consumable::Spectral(spectral) => spectral.name(),
+        }
+    }
+
+    /// Shop price before discounts.
+    pub fn base_cost(&self) -> i64 {
+        match self {
+            Consumable::Spectral(_) => 4,
+            _ => 3,
+        }
+    }
+}
+
+impl fmt::Display for Consumable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl fmt::Debug for Consumable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Consumable {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(tarot) = s.parse() {
+            return Ok(Consumable::Tarot(tarot));
+        }
+        if let Ok(planet) = s.parse() {
+            return Ok(Consumable::Planet(planet));
+        }
+        if let Ok(spectral) = s.parse() {
+            return Ok(Consumable::Spectral(spectral));
+        }
+        Err(format!("Invalid Consumable: `{s}`"))
+    }
+}
+
+string_serde!(Tarot, Planet, Spectral, Consumable);
@@ -0,0 +1,238 @@
+use std::{error::Error, fmt};
+
+use crate::model::{Card, DeckVariant, Edition, JokerCard, Rank, Round, Suit};
+
+use crate::rng::Rng;
+
+/// Number of cards a hand is refilled to by default.
+pub const DEFAULT_HAND_SIZE: usize = 8;
+
+/// The most cards that can be played or discarded at once.
+pub const MAX_SELECTED_CARDS: usize = 5;
+
+pub const ALL_SUITS: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
+
+pub const ALL_RANKS: [Rank; 13] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
+
+/// Number of cards in a standard deck.
+pub const STANDARD_DECK_SIZE: usize = ALL_SUITS.len() * ALL_RANKS.len();
+
+/// Number of cards in the Abandoned Deck, which has no Jacks, Queens or Kings.
+pub const ABANDONED_DECK_SIZE: usize = STANDARD_DECK_SIZE - 3 * ALL_SUITS.len();
+
+#[derive(Debug, PartialEq)]
+pub enum DeckError {
+    NoCardsSelected,
+    TooManyCardsSelected(usize),
+    InvalidCardIndex(usize),
+    DuplicateCardIndex(usize),
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeckError::NoCardsSelected => write!(f, "no cards selected"),
+            DeckError::TooManyCardsSelected(count) => write!(
+                f,
+                "{} cards selected, at most {} allowed",
+                count, MAX_SELECTED_CARDS
+            ),
+            DeckError::InvalidCardIndex(index) => {
+                write!(f, "card index {} is not in the hand", index)
+            }
+            DeckError::DuplicateCardIndex(index) => {
+                write!(f, "card index {} selected more than once", index)
+            }
+        }
+    }
+}
+
+impl Error for DeckError {}
+
+/// Builds the 52 cards of a standard deck.
+pub fn standard_cards() -> Vec<Card> {
+    let mut cards = Vec::new();
+    for suit in ALL_SUITS {
+        for rank in ALL_RANKS {
+            cards.push(Card::new(rank, suit, None, None));
+        }
+    }
+    cards
+}
+
+/// Builds the starting cards of a deck variant; `seed` draws the Erratic Deck's cards.
+pub fn starting_cards(variant: Option<DeckVariant>, seed: u64) -> Vec<Card> {
+    let mut cards = standard_cards();
+    match variant {
+        Some(DeckVariant::Checkered) => {
+            for card in &mut cards {
+                card.suit = match card.suit {
+                    Suit::Clubs => Suit::Spades,
+                    Suit::Diamonds => Suit::Hearts,
+                    suit => suit,
+                };
+            }
+        }
+        Some(DeckVariant::Abandoned) => cards.retain(|card| !card.rank.is_face()),
+        Some(DeckVariant::Erratic) => {
+            let mut rng = Rng::new(seed);
+            for card in &mut cards {
+                card.rank = ALL_RANKS[rng.below(ALL_RANKS.len())];
+                card.suit = ALL_SUITS[rng.below(ALL_SUITS.len())];
+            }
+        }
+        _ => {}
+    }
+    cards
+}
+
+/// A deck split into its draw pile, the current hand and the cards already used.
+#[derive(Clone, Debug)]
+pub struct Deck {
+    draw_pile: Vec<Card>,
+    hand: Vec<Card>,
+    played: Vec<Card>,
+    discarded: Vec<Card>,
+    hand_size: usize,
+}
+
+impl Deck {
+    /// Creates an unshuffled standard 52 card deck.
+    pub fn standard() -> Self {
+        Deck::from_cards(standard_cards())
+    }
+
+    /// Creates a deck from a custom composition; cards keep their enhancements and editions.
+    pub fn from_cards(cards: Vec<Card>) -> Self {
+        Deck {
+            draw_pile: cards,
+            hand: Vec::new(),
+            played: Vec::new(),
+            discarded: Vec::new(),
+            hand_size: DEFAULT_HAND_SIZE,
+        }
+    }
+
+    /// Sets the number of cards the hand is refilled to.
+    pub fn with_hand_size(mut self, hand_size: usize) -> Self {
+        self.hand_size = hand_size;
+        self
+    }
+
+    /// Shuffles the draw pile deterministically for the given seed.
+    pub fn shuffle(&mut self, seed: u64) {
+        Rng::new(seed).shuffle(&mut self.draw_pile);
+    }
+
+    /// Draws from the top of the draw pile until the hand is full, returning the cards drawn.
+    ///
+    /// Each Negative card in hand raises the hand size by one.
+    pub fn draw_to_hand_size(&mut self) -> Vec<Card> {
+        let mut drawn = Vec::new();
+        while self.hand.len() < self.current_hand_size() && !self.draw_pile.is_empty() {
+            let card = self.draw_pile.remove(0);
+            self.hand.push(card);
+            drawn.push(card);
+        }
+        drawn
+    }
+
+    /// Hand size including the bonus from Negative cards in hand.
+    fn current_hand_size(&self) -> usize {
+        let negatives = self
+            .hand
+            .iter()
+            .filter(|card| card.edition == Some(Edition::Negative))
+            .count();
+        self.hand_size + negatives
+    }
+
+    /// Plays the selected hand cards, returning the round to be scored.
+    pub fn play(&mut self, indices: &[usize], jokers: &[JokerCard]) -> Result<Round, DeckError> {
+        let cards_played = self.take_from_hand(indices)?;
+        self.played.extend_from_slice(&cards_played);
+
+        Ok(Round::new(cards_played, self.hand.clone(), jokers.to_vec()))
+    }
+
+    /// Discards the selected hand cards.
+    pub fn discard(&mut self, indices: &[usize]) -> Result<Vec<Card>, DeckError> {
+        let cards = self.take_from_hand(indices)?;
+        self.discarded.extend_from_slice(&cards);
+        Ok(cards)
+    }
+
+    /// Removes the selected cards from the hand, keeping the rest in order.
+    fn take_from_hand(&mut self, indices: &[usize]) -> Result<Vec<Card>, DeckError> {
+        if indices.is_empty() {
+            return Err(DeckError::NoCardsSelected);
+        }
+        if indices.len() > MAX_SELECTED_CARDS {
+            return Err(DeckError::TooManyCardsSelected(indices.len()));
+        }
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.hand.len() {
+                return Err(DeckError::InvalidCardIndex(index));
+            }
+            if indices[..i].contains(&index) {
+                return Err(DeckError::DuplicateCardIndex(index));
+            }
+        }
+
+        let selected: Vec<Card> = indices.iter().map(|&index| self.hand[index]).collect();
+        let mut index = 0;
+        self.hand.retain(|_| {
+            let keep = !indices.contains(&index);
+            index += 1;
+            keep
+        });
+        Ok(selected)
+    }
+
+    pub fn hand(&self) -> &[Card] {
+        &self.hand
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn draw_pile(&self) -> &[Card] {
+        &self.draw_pile
+    }
+
+    pub fn cards_remaining(&self) -> usize {
+        self.draw_pile.len()
+    }
+
+    pub fn played(&self) -> &[Card] {
+        &self.played
+    }
+
+    pub fn discarded(&self) -> &[Card] {
+        &self.discarded
+    }
+
+    /// Returns every card the deck owns, wherever it currently is.
+    pub fn full_deck(&self) -> Vec<Card> {
+        let mut cards = self.draw_pile.clone();
+        cards.extend_from_slice(&self.hand);
+        cards.extend_from_slice(&self.played);
+        cards.extend_from_slice(&self.discarded);
+        cards
+    }
+}
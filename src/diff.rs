@@ -0,0 +1,256 @@
+use std::fmt;
+
+use crate::bignum::{BigNum, Chips, Mult};
+use crate::model::Round;
+use crate::ops::TracedOp;
+use crate::score::{ScoreError, ScoreManager};
+
+/// One scoring step and how much it changed Chips and Mult.
+pub struct StepDelta {
+    pub source: String,
+    pub op: String,
+    pub chips: Chips,
+    pub mult: Mult,
+}
+
+impl fmt::Display for StepDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} (Chips {}, Mult {})",
+            self.source,
+            self.op,
+            signed(&self.chips),
+            signed(&self.mult)
+        )
+    }
+}
+
+/// A scoring step present in only one of the two rounds.
+pub enum StepChange {
+    Removed(StepDelta),
+    Added(StepDelta),
+}
+
+/// One side of a comparison.
+pub struct RoundSummary {
+    pub hand_name: Option<String>,
+    pub chips: Chips,
+    pub mult: Mult,
+    pub score: BigNum,
+}
+
+/// What changed between two rounds and how it moved the score.
+pub struct RoundDiff {
+    pub before: RoundSummary,
+    pub after: RoundSummary,
+    pub played_removed: Vec<String>,
+    pub played_added: Vec<String>,
+    pub held_removed: Vec<String>,
+    pub held_added: Vec<String>,
+    pub jokers_removed: Vec<String>,
+    pub jokers_added: Vec<String>,
+    /// Scoring steps that differ, in the order they were applied.
+    pub steps: Vec<StepChange>,
+}
+
+impl fmt::Display for RoundDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let before_hand = self.before.hand_name.as_deref().unwrap_or("No hand");
+        let after_hand = self.after.hand_name.as_deref().unwrap_or("No hand");
+        if before_hand == after_hand {
+            writeln!(f, "Hand: {}", after_hand)?;
+        } else {
+            writeln!(f, "Hand: {} -> {}", before_hand, after_hand)?;
+        }
+
+        write_changes(f, "Played cards", &self.played_removed, &self.played_added)?;
+        write_changes(f, "Held cards", &self.held_removed, &self.held_added)?;
+        write_changes(f, "Jokers", &self.jokers_removed, &self.jokers_added)?;
+
+        if !self.steps.is_empty() {
+            writeln!(f, "Diverging steps:")?;
+            for step in &self.steps {
+                match step {
+                    StepChange::Removed(delta) => writeln!(f, "  - {}", delta)?,
+                    StepChange::Added(delta) => writeln!(f, "  + {}", delta)?,
+                }
+            }
+        }
+
+        writeln!(
+            f,
+            "Chips: {} -> {} ({})",
+            self.before.chips,
+            self.after.chips,
+            signed(&(&self.after.chips - &self.before.chips))
+        )?;
+        writeln!(
+            f,
+            "Mult: {} -> {} ({})",
+            self.before.mult,
+            self.after.mult,
+            signed(&(&self.after.mult - &self.before.mult))
+        )?;
+        write!(
+            f,
+            "Score: {} -> {} ({})",
+            self.before.score,
+            self.after.score,
+            signed(&(&self.after.score - &self.before.score))
+        )
+    }
+}
+
+fn write_changes(
+    f: &mut fmt::Formatter<'_>,
+    label: &str,
+    removed: &[String],
+    added: &[String],
+) -> fmt::Result {
+    for name in removed {
+        writeln!(f, "{}: - {}", label, name)?;
+    }
+    for name in added {
+        writeln!(f, "{}: + {}", label, name)?;
+    }
+    Ok(())
+}
+
+fn signed(value: &BigNum) -> String {
+    if *value >= BigNum::zero() {
+        format!("+{}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Scores two rounds and explains how the second differs from the first.
+pub fn diff_rounds(before: &Round, after: &Round) -> Result<RoundDiff, ScoreError> {
+    let (before_summary, before_trace) = score_round(before)?;
+    let (after_summary, after_trace) = score_round(after)?;
+
+    let (played_removed, played_added) = changed(
+        before.cards_played.iter().map(|c| c.to_string()),
+        after.cards_played.iter().map(|c| c.to_string()),
+    );
+    let (held_removed, held_added) = changed(
+        before.cards_held_in_hand.iter().map(|c| c.to_string()),
+        after.cards_held_in_hand.iter().map(|c| c.to_string()),
+    );
+    let (jokers_removed, jokers_added) = changed(
+        before.jokers.iter().map(|j| j.to_string()),
+        after.jokers.iter().map(|j| j.to_string()),
+    );
+
+    Ok(RoundDiff {
+        before: before_summary,
+        after: after_summary,
+        played_removed,
+        played_added,
+        held_removed,
+        held_added,
+        jokers_removed,
+        jokers_added,
+        steps: diverging_steps(step_deltas(&before_trace), step_deltas(&after_trace)),
+    })
+}
+
+fn score_round(round: &Round) -> Result<(RoundSummary, Vec<TracedOp>), ScoreError> {
+    let mut manager = ScoreManager::from_round(round);
+    let score = manager.calculate_score()?;
+    let trace = manager.trace().to_vec();
+    let (chips, mult) = trace.last().map_or_else(
+        || (BigNum::zero(), BigNum::zero()),
+        |last| (last.chips.clone(), last.mult.clone()),
+    );
+    let summary = RoundSummary {
+        hand_name: manager.best_hand_name().map(str::to_string),
+        chips,
+        mult,
+        score,
+    };
+    Ok((summary, trace))
+}
+
+/// Names in `before` but not `after`, and in `after` but not `before`, counting repeats.
+fn changed(
+    before: impl Iterator<Item = String>,
+    after: impl Iterator<Item = String>,
+) -> (Vec<String>, Vec<String>) {
+    let mut removed: Vec<String> = before.collect();
+    let mut added = Vec::new();
+    for name in after {
+        match removed.iter().position(|other| *other == name) {
+            Some(index) => {
+                removed.remove(index);
+            }
+            None => added.push(name),
+        }
+    }
+    (removed, added)
+}
+
+fn step_deltas(trace: &[TracedOp]) -> Vec<StepDelta> {
+    let mut chips = BigNum::zero();
+    let mut mult = BigNum::zero();
+    trace
+        .iter()
+        .map(|traced| {
+            let delta = StepDelta {
+                source: traced.source.to_string(),
+                op: traced.op.to_string(),
+                chips: &traced.chips - &chips,
+                mult: &traced.mult - &mult,
+            };
+            chips = traced.chips.clone();
+            mult = traced.mult.clone();
+            delta
+        })
+        .collect()
+}
+
+/// Aligns two traces by their longest common run of steps and returns the rest.
+fn diverging_steps(before: Vec<StepDelta>, after: Vec<StepDelta>) -> Vec<StepChange> {
+    let same = |a: &StepDelta, b: &StepDelta| a.source == b.source && a.op == b.op;
+
+    let mut common = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            common[i][j] = if same(&before[i], &after[j]) {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut steps = Vec::new();
+    let mut before = before.into_iter().peekable();
+    let mut after = after.into_iter().peekable();
+    let (mut i, mut j) = (0, 0);
+    loop {
+        match (before.peek(), after.peek()) {
+            (Some(a), Some(b)) if same(a, b) => {
+                before.next();
+                after.next();
+                i += 1;
+                j += 1;
+            }
+            (Some(_), Some(_)) if common[i + 1][j] >= common[i][j + 1] => {
+                steps.push(StepChange::Removed(before.next().unwrap()));
+                i += 1;
+            }
+            (_, Some(_)) => {
+                steps.push(StepChange::Added(after.next().unwrap()));
+                j += 1;
+            }
+            (Some(_), None) => {
+                steps.push(StepChange::Removed(before.next().unwrap()));
+                i += 1;
+            }
+            (None, None) => break,
+        }
+    }
+    steps
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// Most interest that can be earned in one round.
+pub const INTEREST_CAP: i64 = 5;
+
+/// Interest paid at end of round: $1 for every $5 held, up to `INTEREST_CAP`.
+pub fn interest(money: i64) -> i64 {
+    (money.max(0) / 5).min(INTEREST_CAP)
+}
+
+/// Dollars earned by playing a hand or a blind, split by when they are paid out.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Earnings {
+    pub starting_money: i64,
+    pub during_scoring: i64,
+    pub end_of_round: i64,
+    /// Charged for Rental jokers at the end of the round.
+    pub rent: i64,
+}
+
+impl Earnings {
+    pub fn total(&self) -> i64 {
+        self.during_scoring + self.end_of_round - self.rent
+    }
+
+    pub fn final_money(&self) -> i64 {
+        self.starting_money + self.total()
+    }
+}
+
+impl fmt::Display for Earnings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Money: ${} -> ${} (+${} during scoring, +${} at end of round",
+            self.starting_money,
+            self.final_money(),
+            self.during_scoring,
+            self.end_of_round
+        )?;
+        if self.rent != 0 {
+            write!(f, ", -${} rent", self.rent)?;
+        }
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,99 @@
+use std::{error::Error, fmt, path::Path};
+
+use clap::ValueEnum;
+use serde::de::DeserializeOwned;
+
+use crate::validate::Location;
+
+/// Text formats input files can be written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum InputFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl InputFormat {
+    /// Picks the format from a file extension, defaulting to YAML.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => InputFormat::Json,
+            Some("toml") => InputFormat::Toml,
+            _ => InputFormat::Yaml,
+        }
+    }
+}
+
+/// Why an input file could not be parsed.
+#[derive(Debug)]
+pub enum InputError {
+    Yaml(serde_yaml::Error),
+    Json(serde_json::Error),
+    Toml(toml::de::Error),
+}
+
+impl InputError {
+    /// Where in the input the error was found.
+    pub fn location(&self, source: &str) -> Option<Location> {
+        match self {
+            InputError::Yaml(error) => error.location().map(|location| Location {
+                line: location.line(),
+                column: location.column(),
+            }),
+            InputError::Json(error) if error.line() > 0 => Some(Location {
+                line: error.line(),
+                column: error.column(),
+            }),
+            InputError::Json(_) => None,
+            InputError::Toml(error) => error.span().map(|span| {
+                let before = &source[..span.start];
+                let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+                Location {
+                    line: before.matches('\n').count() + 1,
+                    column: before[line_start..].chars().count() + 1,
+                }
+            }),
+        }
+    }
+
+    /// The error without its location.
+    pub fn message(&self) -> String {
+        let message = self.to_string();
+        match self {
+            InputError::Yaml(_) | InputError::Json(_) => match message.rfind(" at line ") {
+                Some(end) => message[..end].to_string(),
+                None => message,
+            },
+            InputError::Toml(error) => error.message().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Yaml(error) => write!(f, "{}", error),
+            InputError::Json(error) => write!(f, "{}", error),
+            InputError::Toml(error) => write!(f, "{}", error.to_string().trim_end()),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Yaml(error) => Some(error),
+            InputError::Json(error) => Some(error),
+            InputError::Toml(error) => Some(error),
+        }
+    }
+}
+
+/// Parses an input file written in the given format.
+pub fn parse<T: DeserializeOwned>(source: &str, format: InputFormat) -> Result<T, InputError> {
+    match format {
+        InputFormat::Yaml => serde_yaml::from_str(source).map_err(InputError::Yaml),
+        InputFormat::Json => serde_json::from_str(source).map_err(InputError::Json),
+        InputFormat::Toml => toml::from_str(source).map_err(InputError::Toml),
+    }
+}
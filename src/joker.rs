@@ -1,12 +1,47 @@
-use ortalib::{Card, Chips, JokerCard, Mult, Suit};
+use crate::model::{Card, Edition, Enhancement, Joker, JokerCard, Suit, occupied_joker_slots};
 use std::collections::HashMap;
 
+use crate::bignum::BigNum;
+use crate::model::RunState;
 use crate::modifiers;
-
-pub enum JokerActivation {
-    OnScored,
-    OnHeld,
+use crate::ops::{OpSource, ScoreOp, SourcedOp};
+use crate::rng::Rng;
+
+/// A point in a round that jokers can react to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    /// The blind is chosen, before any cards are drawn.
+    BlindSelected,
+    /// A hand is played and its poker hand found, before any card scores.
+    BeforeScoring,
+    /// A played card scores.
+    CardScored,
+    /// A card held in hand is evaluated after the played cards.
+    CardHeld,
+    /// Jokers act on their own once every card has been evaluated.
     Independent,
+    /// The hand has been scored.
+    AfterHand,
+    /// A card is discarded.
+    Discard,
+    /// A card is destroyed, such as by The Hanged Man or Immolate.
+    CardDestroyed,
+    /// The round ends and money is paid out.
+    EndOfRound,
+}
+
+/// What a joker sees when it handles a phase.
+pub struct JokerEvent<'e> {
+    pub phase: Phase,
+    /// The card the phase is about, for per-card phases.
+    pub card: Option<&'e Card>,
+    pub rng: &'e mut Rng,
+}
+
+impl<'e> JokerEvent<'e> {
+    pub fn new(phase: Phase, card: Option<&'e Card>, rng: &'e mut Rng) -> Self {
+        JokerEvent { phase, card, rng }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -17,30 +52,30 @@ pub enum ScoringScope {
 }
 pub trait JokerEffect {
     fn name(&self) -> &'static str;
-    fn activation_type(&self) -> JokerActivation;
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool;
+    /// The phases this joker subscribes to.
+    fn phases(&self) -> &'static [Phase];
+    /// Whether the joker subscribes to `phase`.
+    fn handles(&self, phase: Phase) -> bool {
+        self.phases().contains(&phase)
+    }
+    /// Handles an event of a subscribed phase, returning the operations to apply.
+    ///
+    /// By default this is the joker's effect on Chips and Mult.
+    fn on_phase(&self, event: &mut JokerEvent, context: &JokerContext) -> Vec<ScoreOp> {
+        self.apply(event.card, context)
+    }
+    /// The joker's effect on Chips and Mult, given the card being scored or held.
+    fn apply(&self, _card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
+        Vec::new()
+    }
     fn scoring_scope(&self, _context: &JokerContext) -> ScoringScope {
         ScoringScope::BestHand
     }
-    fn supports_retrigger(&self) -> bool {
-        false
-    }
     fn is_passive(&self) -> bool {
         false
     }
-    fn copy_effect(
-        &self,
-        _chips: &mut Chips,
-        _mult: &mut Mult,
-        _context: &JokerContext,
-    ) -> Option<String> {
-        None
+    fn copy_effect(&self, _context: &JokerContext) -> Vec<ScoreOp> {
+        Vec::new()
     }
     fn is_copyable(&self) -> bool {
         true
@@ -55,6 +90,9 @@ pub struct JokerContext<'a> {
     pub cards_in_hand: &'a [Card],
     pub best_hand_name: Option<&'a str>,
     pub all_jokers: &'a [JokerCard],
+    /// Jokers that hold a slot but are skipped when scoring.
+    pub debuffed_jokers: &'a [JokerCard],
+    pub run: &'a RunState,
 }
 impl<'a> JokerContext<'a> {
     pub fn new(
@@ -62,30 +100,54 @@ impl<'a> JokerContext<'a> {
         cards_in_hand: &'a [Card],
         best_hand_name: Option<&'a str>,
         all_jokers: &'a [JokerCard],
+        debuffed_jokers: &'a [JokerCard],
+        run: &'a RunState,
     ) -> Self {
         JokerContext {
             cards_played,
             cards_in_hand,
             best_hand_name,
             all_jokers,
+            debuffed_jokers,
+            run,
         }
     }
+
+    /// Number of jokers held, including debuffed ones.
+    pub fn joker_count(&self) -> usize {
+        self.all_jokers.len() + self.debuffed_jokers.len()
+    }
+
+    /// Joker slots left empty, after Negative jokers add their own.
+    pub fn empty_joker_slots(&self) -> usize {
+        let occupied =
+            occupied_joker_slots(self.all_jokers) + occupied_joker_slots(self.debuffed_jokers);
+        self.run.joker_slot_count().saturating_sub(occupied)
+    }
     pub fn is_face_card(&self, card: &Card) -> bool {
         if self
             .all_jokers
             .iter()
-            .any(|joker| matches!(joker.joker, ortalib::Joker::Pareidolia))
+            .any(|joker| matches!(joker.joker, Joker::Pareidolia))
         {
             return true;
         }
 
         card.rank.is_face()
     }
+    /// Cards left in the draw pile, defaulting to the deck minus the cards in play.
+    pub fn cards_in_deck(&self) -> usize {
+        self.run.cards_in_deck.unwrap_or_else(|| {
+            self.run
+                .deck_size()
+                .saturating_sub(self.cards_played.len() + self.cards_in_hand.len())
+        })
+    }
     pub fn with_modified_suits(&self) -> Vec<Card> {
         let has_smeared_joker = self
             .all_jokers
             .iter()
-            .any(|j| matches!(j.joker, ortalib::Joker::SmearedJoker));
+            .any(|j| matches!(j.joker, Joker::SmearedJoker));
 
         if has_smeared_joker {
             self.cards_played
@@ -112,19 +174,12 @@ impl JokerEffect for BasicJoker {
         "Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
-        *mult += 4.0;
-        false
+    fn apply(&self, _card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
+        vec![ScoreOp::AddMult(BigNum::from(4))]
     }
 }
 
@@ -135,28 +190,21 @@ impl JokerEffect for JollyJoker {
         "Jolly Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         let mut rank_counts = HashMap::new();
         for card in context.cards_played {
             *rank_counts.entry(card.rank).or_insert(0) += 1;
         }
 
         if rank_counts.values().any(|&count| count >= 2) {
-            *mult += 8.0;
-            return true;
+            return vec![ScoreOp::AddMult(BigNum::from(8))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -167,28 +215,21 @@ impl JokerEffect for ZanyJoker {
         "Zany Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         let mut rank_counts = HashMap::new();
         for card in context.cards_played {
             *rank_counts.entry(card.rank).or_insert(0) += 1;
         }
 
         if rank_counts.values().any(|&count| count >= 3) {
-            *mult += 12.0;
-            return true;
+            return vec![ScoreOp::AddMult(BigNum::from(12))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -199,17 +240,11 @@ impl JokerEffect for MadJoker {
         "Mad Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         let mut rank_counts = HashMap::new();
         for card in context.cards_played {
             *rank_counts.entry(card.rank).or_insert(0) += 1;
@@ -218,11 +253,10 @@ impl JokerEffect for MadJoker {
         let pairs_count = rank_counts.values().filter(|&&count| count >= 2).count();
 
         if pairs_count >= 2 {
-            *mult += 10.0;
-            return true;
+            return vec![ScoreOp::AddMult(BigNum::from(10))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -233,23 +267,16 @@ impl JokerEffect for CrazyJoker {
         "Crazy Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         if has_straight(context.cards_played) {
-            *mult += 12.0;
-            return true;
+            return vec![ScoreOp::AddMult(BigNum::from(12))];
         }
 
-        false
+        Vec::new()
     }
 }
 /// Checks if a straight is present in the cards.
@@ -305,23 +332,16 @@ impl JokerEffect for DrollJoker {
         "Droll Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         if has_flush(context.cards_played) {
-            *mult += 10.0;
-            return true;
+            return vec![ScoreOp::AddMult(BigNum::from(10))];
         }
 
-        false
+        Vec::new()
     }
 }
 /// Checks if a flush is present in the cards.
@@ -345,28 +365,21 @@ impl JokerEffect for SlyJoker {
         "Sly Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         let mut rank_counts = HashMap::new();
         for card in context.cards_played {
             *rank_counts.entry(card.rank).or_insert(0) += 1;
         }
 
         if rank_counts.values().any(|&count| count >= 2) {
-            *chips += 50.0;
-            return true;
+            return vec![ScoreOp::AddChips(BigNum::from(50))];
         }
 
-        false
+        Vec::new()
     }
 }
 pub struct WilyJoker;
@@ -376,28 +389,21 @@ impl JokerEffect for WilyJoker {
         "Wily Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         let mut rank_counts = HashMap::new();
         for card in context.cards_played {
             *rank_counts.entry(card.rank).or_insert(0) += 1;
         }
 
         if rank_counts.values().any(|&count| count >= 3) {
-            *chips += 100.0;
-            return true;
+            return vec![ScoreOp::AddChips(BigNum::from(100))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -408,17 +414,11 @@ impl JokerEffect for CleverJoker {
         "Clever Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         let mut rank_counts = HashMap::new();
         for card in context.cards_played {
             *rank_counts.entry(card.rank).or_insert(0) += 1;
@@ -427,11 +427,10 @@ impl JokerEffect for CleverJoker {
         let pairs_count = rank_counts.values().filter(|&&count| count == 2).count();
 
         if pairs_count >= 2 {
-            *chips += 80.0;
-            return true;
+            return vec![ScoreOp::AddChips(BigNum::from(80))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -442,23 +441,16 @@ impl JokerEffect for DeviousJoker {
         "Devious Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         if has_straight(context.cards_played) {
-            *chips += 100.0;
-            return true;
+            return vec![ScoreOp::AddChips(BigNum::from(100))];
         }
 
-        false
+        Vec::new()
     }
 }
 pub struct CraftyJoker;
@@ -468,23 +460,16 @@ impl JokerEffect for CraftyJoker {
         "Crafty Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         if has_flush(context.cards_played) {
-            *chips += 80.0;
-            return true;
+            return vec![ScoreOp::AddChips(BigNum::from(80))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -495,20 +480,14 @@ impl JokerEffect for AbstractJoker {
         "Abstract Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        let joker_count = context.all_jokers.len();
-        *mult += 3.0 * joker_count as f64;
-        true
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        vec![ScoreOp::AddMult(BigNum::from(
+            3 * context.joker_count() as i64,
+        ))]
     }
 }
 
@@ -519,34 +498,22 @@ impl JokerEffect for RaisedFistJoker {
         "Raised Fist"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnHeld
-    }
-    fn supports_retrigger(&self) -> bool {
-        false
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardHeld]
     }
-
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if let Some(lowest_card) = find_lowest_rank_card(context.cards_in_hand) {
-                if card == lowest_card {
-                    let bonus_value = match card.rank {
-                        ortalib::Rank::Jack | ortalib::Rank::Queen | ortalib::Rank::King => 20.0,
-                        ortalib::Rank::Ace => 22.0,
-                        _ => card.rank.rank_value() * 2.0,
-                    };
-                    *mult += bonus_value;
-                    return true;
-                }
-            }
+    fn apply(&self, card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && let Some(lowest_card) = find_lowest_rank_card(context.cards_in_hand)
+            && card == lowest_card
+        {
+            let bonus_value = match card.rank {
+                ortalib::Rank::Jack | ortalib::Rank::Queen | ortalib::Rank::King => 20,
+                ortalib::Rank::Ace => 22,
+                _ => card.rank.rank_value() as i64 * 2,
+            };
+            return vec![ScoreOp::AddMult(BigNum::from(bonus_value))];
         }
-        false
+        Vec::new()
     }
 }
 
@@ -585,33 +552,25 @@ impl JokerEffect for BlackboardJoker {
         "Blackboard"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         if context.cards_in_hand.is_empty() {
-            *mult *= 3.0;
-            return true;
+            return vec![ScoreOp::MulMult(BigNum::from(3))];
         }
 
         let all_black = context.cards_in_hand.iter().all(|card| {
             matches!(card.suit, ortalib::Suit::Spades | ortalib::Suit::Clubs)
-                || matches!(card.enhancement, Some(ortalib::Enhancement::Wild))
+                || matches!(card.enhancement, Some(Enhancement::Wild))
         });
 
         if all_black {
-            *mult *= 3.0;
-            return true;
+            return vec![ScoreOp::MulMult(BigNum::from(3))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -622,24 +581,18 @@ impl JokerEffect for BaronJoker {
         "Baron"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnHeld
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardHeld]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if context.is_face_card(card) && card.rank == ortalib::Rank::King {
-                *mult *= 1.5;
-                return true;
-            }
+    fn apply(&self, card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && context.is_face_card(card)
+            && card.rank == ortalib::Rank::King
+        {
+            return vec![ScoreOp::MulMult(BigNum::new(15, 1))];
         }
-        false
+        Vec::new()
     }
 }
 
@@ -650,27 +603,19 @@ impl JokerEffect for GreedyJoker {
         "Greedy Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if matches!(card.suit, ortalib::Suit::Diamonds)
-                || matches!(card.enhancement, Some(ortalib::Enhancement::Wild))
-            {
-                *mult += 3.0;
-                return true;
-            }
+    fn apply(&self, card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && (matches!(card.suit, ortalib::Suit::Diamonds)
+                || matches!(card.enhancement, Some(Enhancement::Wild)))
+        {
+            return vec![ScoreOp::AddMult(BigNum::from(3))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -681,27 +626,19 @@ impl JokerEffect for LustyJoker {
         "Lusty Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if matches!(card.suit, ortalib::Suit::Hearts)
-                || matches!(card.enhancement, Some(ortalib::Enhancement::Wild))
-            {
-                *mult += 3.0;
-                return true;
-            }
+    fn apply(&self, card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && (matches!(card.suit, ortalib::Suit::Hearts)
+                || matches!(card.enhancement, Some(Enhancement::Wild)))
+        {
+            return vec![ScoreOp::AddMult(BigNum::from(3))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -712,27 +649,19 @@ impl JokerEffect for WrathfulJoker {
         "Wrathful Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if matches!(card.suit, ortalib::Suit::Spades)
-                || matches!(card.enhancement, Some(ortalib::Enhancement::Wild))
-            {
-                *mult += 3.0;
-                return true;
-            }
+    fn apply(&self, card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && (matches!(card.suit, ortalib::Suit::Spades)
+                || matches!(card.enhancement, Some(Enhancement::Wild)))
+        {
+            return vec![ScoreOp::AddMult(BigNum::from(3))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -743,27 +672,19 @@ impl JokerEffect for GluttonousJoker {
         "Gluttonous Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if matches!(card.suit, ortalib::Suit::Clubs)
-                || matches!(card.enhancement, Some(ortalib::Enhancement::Wild))
-            {
-                *mult += 3.0;
-                return true;
-            }
+    fn apply(&self, card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && (matches!(card.suit, ortalib::Suit::Clubs)
+                || matches!(card.enhancement, Some(Enhancement::Wild)))
+        {
+            return vec![ScoreOp::AddMult(BigNum::from(3))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -774,17 +695,11 @@ impl JokerEffect for FibonacciJoker {
         "Fibonacci"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
         if let Some(card) = card {
             let is_fibonacci = matches!(
                 card.rank,
@@ -796,12 +711,11 @@ impl JokerEffect for FibonacciJoker {
             );
 
             if is_fibonacci {
-                *mult += 8.0;
-                return true;
+                return vec![ScoreOp::AddMult(BigNum::from(8))];
             }
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -812,25 +726,18 @@ impl JokerEffect for ScaryFaceJoker {
         "Scary Face"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        _mult: &mut Mult,
-        card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if context.is_face_card(card) {
-                *chips += 30.0;
-                return true;
-            }
+    fn apply(&self, card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && context.is_face_card(card)
+        {
+            return vec![ScoreOp::AddChips(BigNum::from(30))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -841,34 +748,27 @@ impl JokerEffect for EvenStevenJoker {
         "Even Steven"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
         if let Some(card) = card {
             let rank_value = card.rank.rank_value() as u8;
-            if rank_value % 2 == 0 && (2..=10).contains(&rank_value) {
+            if rank_value.is_multiple_of(2) && (2..=10).contains(&rank_value) {
                 let bonus = match rank_value {
-                    10 => 4.0,
-                    8 => 4.0,
-                    6 => 4.0,
-                    4 => 4.0,
-                    2 => 4.0,
-                    _ => 0.0,
+                    10 => 4,
+                    8 => 4,
+                    6 => 4,
+                    4 => 4,
+                    2 => 4,
+                    _ => 0,
                 };
-                *mult += bonus;
-                return true;
+                return vec![ScoreOp::AddMult(BigNum::from(bonus))];
             }
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -879,29 +779,22 @@ impl JokerEffect for OddToddJoker {
         "Odd Todd"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        _mult: &mut Mult,
-        card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
         if let Some(card) = card {
             let rank_value = card.rank.rank_value() as u8;
 
             if (rank_value % 2 == 1 && (3..=9).contains(&rank_value))
                 || matches!(card.rank, ortalib::Rank::Ace)
             {
-                *chips += 31.0;
-                return true;
+                return vec![ScoreOp::AddChips(BigNum::from(31))];
             }
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -912,36 +805,28 @@ impl JokerEffect for PhotographJoker {
         "Photograph"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if context.is_face_card(card) {
-                if let Some(card_index) = context.cards_played.iter().position(|c| c == card) {
-                    let previous_face_cards = context
-                        .cards_played
-                        .iter()
-                        .take(card_index)
-                        .filter(|c| c.rank.is_face())
-                        .count();
-
-                    if previous_face_cards == 0 {
-                        *mult *= 2.0;
-                        return true;
-                    }
-                }
+    fn apply(&self, card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && context.is_face_card(card)
+            && let Some(card_index) = context.cards_played.iter().position(|c| c == card)
+        {
+            let previous_face_cards = context
+                .cards_played
+                .iter()
+                .take(card_index)
+                .filter(|c| c.rank.is_face())
+                .count();
+
+            if previous_face_cards == 0 {
+                return vec![ScoreOp::MulMult(BigNum::from(2))];
             }
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -952,25 +837,18 @@ impl JokerEffect for SmileyFaceJoker {
         "Smiley Face"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if context.is_face_card(card) {
-                *mult += 5.0;
-                return true;
-            }
+    fn apply(&self, card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && context.is_face_card(card)
+        {
+            return vec![ScoreOp::AddMult(BigNum::from(5))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -980,25 +858,19 @@ impl JokerEffect for FlowerPotJoker {
         "Flower Pot"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
         if context.cards_played.len() < 4 {
-            return false;
+            return Vec::new();
         }
 
         let has_smeared_joker = context
             .all_jokers
             .iter()
-            .any(|j| matches!(j.joker, ortalib::Joker::SmearedJoker));
+            .any(|j| matches!(j.joker, Joker::SmearedJoker));
 
         let cards = context.cards_played;
         let mut diamonds_count = 0;
@@ -1014,7 +886,7 @@ impl JokerEffect for FlowerPotJoker {
                 ortalib::Suit::Hearts => hearts_count += 1,
                 ortalib::Suit::Spades => spades_count += 1,
             }
-            if matches!(card.enhancement, Some(ortalib::Enhancement::Wild)) {
+            if matches!(card.enhancement, Some(Enhancement::Wild)) {
                 _wild_cards += 1;
             }
         }
@@ -1046,16 +918,13 @@ impl JokerEffect for FlowerPotJoker {
 
         if has_smeared_joker {
             if hearts_count > 0 && unique_suit_groups >= 2 {
-                *mult *= 3.0;
-                return true;
+                return vec![ScoreOp::MulMult(BigNum::from(3))];
             }
         } else if unique_suits >= 4 {
-                *mult *= 3.0;
-                return true; 
-            
+            return vec![ScoreOp::MulMult(BigNum::from(3))];
         }
 
-        false
+        Vec::new()
     }
 }
 
@@ -1066,18 +935,12 @@ impl JokerEffect for FourFingersJoker {
         "Four Fingers"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
-        false
+    fn apply(&self, _card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
+        Vec::new()
     }
 
     fn is_copyable(&self) -> bool {
@@ -1108,7 +971,7 @@ impl JokerEffect for FourFingersJoker {
         let has_shortcut = context
             .all_jokers
             .iter()
-            .any(|joker| matches!(joker.joker, ortalib::Joker::Shortcut));
+            .any(|joker| matches!(joker.joker, Joker::Shortcut));
 
         if context.best_hand_name.is_some() && (has_royal_cards || has_shortcut) {
             return Some(ScoringScope::AllPlayed);
@@ -1125,19 +988,10 @@ impl JokerEffect for ShortcutJoker {
         "Shortcut"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        _context: &JokerContext,
-    ) -> bool {
-        true
-    }
     fn is_copyable(&self) -> bool {
         false
     }
@@ -1149,41 +1003,15 @@ impl JokerEffect for MimeJoker {
         "Mime"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnHeld
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardHeld]
     }
 
-    fn supports_retrigger(&self) -> bool {
-        false
-    }
-
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            let mut processed_types = std::collections::HashSet::new();
-            processed_types.insert(get_joker_id(&ortalib::Joker::Mime));
-
-            for joker in context.all_jokers {
-                let joker_id = get_joker_id(&joker.joker);
-
-                if !processed_types.insert(joker_id) {
-                    continue;
-                }
-
-                let effect = JokerFactory::create_joker(&joker.joker);
-
-                if matches!(effect.activation_type(), JokerActivation::OnHeld) {
-                    effect.apply(_chips, mult, Some(card), context);
-                }
-            }
-            return true;
+    fn apply(&self, card: Option<&Card>, _context: &JokerContext) -> Vec<ScoreOp> {
+        if card.is_some() {
+            return vec![ScoreOp::Retrigger];
         }
-        false
+        Vec::new()
     }
 }
 pub struct PareidoliaJoker;
@@ -1193,18 +1021,8 @@ impl JokerEffect for PareidoliaJoker {
         "Pareidolia"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
-    }
-
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        !context.cards_played.is_empty() || !context.cards_in_hand.is_empty()
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
     fn is_copyable(&self) -> bool {
@@ -1219,18 +1037,8 @@ impl JokerEffect for SplashJoker {
         "Splash"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
-    }
-
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        !context.cards_played.is_empty()
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
     fn scoring_scope(&self, context: &JokerContext) -> ScoringScope {
@@ -1251,47 +1059,17 @@ impl JokerEffect for SockAndBuskinJoker {
         "Sock and Buskin"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::OnScored
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        mult: &mut Mult,
-        card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        if let Some(card) = card {
-            if context.is_face_card(card) {
-                let value = card.rank.rank_value();
-                *chips += value;
-
-                if let Some(enhancement_type) = &card.enhancement {
-                    let enhancement = modifiers::create_enhancement_handler(enhancement_type);
-                    enhancement.apply(chips, mult, card, false);
-                }
-
-                if let Some(edition_type) = &card.edition {
-                    let edition = modifiers::create_edition_handler(edition_type);
-                    edition.apply(chips, mult, card);
-                }
-
-                for joker in context.all_jokers {
-                    let joker_effect = JokerFactory::create_joker(&joker.joker);
-                    if matches!(joker_effect.activation_type(), JokerActivation::OnScored)
-                        && joker_effect.name() != self.name()
-                    {
-                        joker_effect.apply(chips, mult, Some(card), context);
-                    }
-                }
-                return true;
-            }
+    fn apply(&self, card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = card
+            && context.is_face_card(card)
+        {
+            return vec![ScoreOp::Retrigger];
         }
-        false
-    }
-    fn supports_retrigger(&self) -> bool {
-        false
+        Vec::new()
     }
 }
 
@@ -1302,18 +1080,8 @@ impl JokerEffect for SmearedJoker {
         "Smeared Joker"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
-    }
-
-    fn apply(
-        &self,
-        _chips: &mut Chips,
-        _mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        !context.cards_played.is_empty()
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
     }
 
     fn is_copyable(&self) -> bool {
@@ -1321,99 +1089,318 @@ impl JokerEffect for SmearedJoker {
     }
 }
 
-pub struct BlueprintJoker;
+/// Copies the joker to its right, which is only known once the lineup is.
+#[derive(Default)]
+pub struct BlueprintJoker {
+    target: Option<Box<dyn JokerEffect>>,
+}
 
 impl JokerEffect for BlueprintJoker {
     fn name(&self) -> &'static str {
         "Blueprint"
     }
 
-    fn activation_type(&self) -> JokerActivation {
-        JokerActivation::Independent
+    fn phases(&self) -> &'static [Phase] {
+        self.target.as_ref().map_or(&[], |target| target.phases())
     }
 
-    fn apply(
-        &self,
-        chips: &mut Chips,
-        mult: &mut Mult,
-        _card: Option<&Card>,
-        context: &JokerContext,
-    ) -> bool {
-        if let Some(_copied_joker_name) = self.copy_effect(chips, mult, context) {
-            !context.cards_played.is_empty()
-        } else {
-            false
-        }
-    }
-    /// Copies the effect of the next applicable joker.   
-    fn copy_effect(
-        &self,
-        chips: &mut Chips,
-        mult: &mut Mult,
-        context: &JokerContext,
-    ) -> Option<String> {
-        let mut current_index = None;
-        for (i, joker) in context.all_jokers.iter().enumerate() {
-            let joker_effect = JokerFactory::create_joker(&joker.joker);
-            if joker_effect.name() == self.name() {
-                current_index = Some(i);
-                break;
-            }
+    fn on_phase(&self, event: &mut JokerEvent, context: &JokerContext) -> Vec<ScoreOp> {
+        match &self.target {
+            Some(target) => target.on_phase(event, context),
+            None => Vec::new(),
         }
+    }
 
-        if let Some(mut index) = current_index {
-            let mut target_joker_effect = None;
-            while index + 1 < context.all_jokers.len() {
-                index += 1;
-                let next_joker = &context.all_jokers[index];
-                let next_joker_effect = JokerFactory::create_joker(&next_joker.joker);
+    fn apply(&self, card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        match &self.target {
+            Some(target) => target.apply(card, context),
+            None => Vec::new(),
+        }
+    }
+}
 
-                if next_joker_effect.is_passive() {
-                    continue;
-                }
+impl BlueprintJoker {
+    /// Creates the Blueprint in slot `index` of a lineup, copying the first joker to its
+    /// right that is neither passive nor another Blueprint.
+    pub fn in_lineup(jokers: &[JokerCard], index: usize) -> Self {
+        let target = jokers
+            .iter()
+            .skip(index + 1)
+            .map(|joker| JokerFactory::create_joker(&joker.joker))
+            .find(|effect| !effect.is_passive() && effect.name() != "Blueprint");
+        BlueprintJoker { target }
+    }
+}
 
-                if next_joker_effect.name() == self.name() {
-                    continue;
-                }
+pub struct GoldenJoker;
 
-                target_joker_effect = Some(next_joker_effect);
-                break;
-            }
+impl JokerEffect for GoldenJoker {
+    fn name(&self) -> &'static str {
+        "Golden Joker"
+    }
 
-            if let Some(joker_effect) = target_joker_effect {
-                match joker_effect.activation_type() {
-                    JokerActivation::OnScored => {
-                        let mut applied_any = false;
-                        for card in context.cards_played {
-                            if joker_effect.apply(chips, mult, Some(card), context) {
-                                applied_any = true;
-                            }
-                        }
-                        if applied_any {
-                            return Some(joker_effect.name().to_string());
-                        }
-                    }
-                    JokerActivation::OnHeld => {
-                        let mut applied_any = false;
-                        for card in context.cards_in_hand {
-                            if joker_effect.apply(chips, mult, Some(card), context) {
-                                applied_any = true;
-                            }
-                        }
-                        if applied_any {
-                            return Some(joker_effect.name().to_string());
-                        }
-                    }
-                    JokerActivation::Independent => {
-                        let applied = joker_effect.apply(chips, mult, None, context);
-                        if applied {
-                            return Some(joker_effect.name().to_string());
-                        }
-                    }
-                }
-            }
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::EndOfRound]
+    }
+
+    fn on_phase(&self, _event: &mut JokerEvent, _context: &JokerContext) -> Vec<ScoreOp> {
+        vec![ScoreOp::EarnMoney(4)]
+    }
+}
+
+pub struct BullJoker;
+
+impl JokerEffect for BullJoker {
+    fn name(&self) -> &'static str {
+        "Bull"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        let money = context.run.money.max(0);
+        vec![ScoreOp::AddChips(BigNum::from(2 * money))]
+    }
+}
+
+pub struct BootstrapsJoker;
+
+impl JokerEffect for BootstrapsJoker {
+    fn name(&self) -> &'static str {
+        "Bootstraps"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        let fives = context.run.money.max(0) / 5;
+        vec![ScoreOp::AddMult(BigNum::from(2 * fives))]
+    }
+}
+
+pub struct BusinessCardJoker;
+
+impl JokerEffect for BusinessCardJoker {
+    fn name(&self) -> &'static str {
+        "Business Card"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
+    }
+
+    fn on_phase(&self, event: &mut JokerEvent, context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = event.card
+            && context.is_face_card(card)
+            && event.rng.below(2) == 0
+        {
+            return vec![ScoreOp::EarnMoney(2)];
         }
-        None
+        Vec::new()
+    }
+}
+
+pub struct RoughGemJoker;
+
+impl JokerEffect for RoughGemJoker {
+    fn name(&self) -> &'static str {
+        "Rough Gem"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
+    }
+
+    fn on_phase(&self, event: &mut JokerEvent, _context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(card) = event.card
+            && (matches!(card.suit, ortalib::Suit::Diamonds)
+                || matches!(card.enhancement, Some(Enhancement::Wild)))
+        {
+            return vec![ScoreOp::EarnMoney(1)];
+        }
+        Vec::new()
+    }
+}
+
+pub struct BannerJoker;
+
+impl JokerEffect for BannerJoker {
+    fn name(&self) -> &'static str {
+        "Banner"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        vec![ScoreOp::AddChips(BigNum::from(
+            30 * context.run.discards_left as i64,
+        ))]
+    }
+}
+
+pub struct MysticSummitJoker;
+
+impl JokerEffect for MysticSummitJoker {
+    fn name(&self) -> &'static str {
+        "Mystic Summit"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if context.run.discards_left == 0 {
+            return vec![ScoreOp::AddMult(BigNum::from(15))];
+        }
+        Vec::new()
+    }
+}
+
+pub struct AcrobatJoker;
+
+impl JokerEffect for AcrobatJoker {
+    fn name(&self) -> &'static str {
+        "Acrobat"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if context.run.hands_left == 0 {
+            return vec![ScoreOp::MulMult(BigNum::from(3))];
+        }
+        Vec::new()
+    }
+}
+
+pub struct DuskJoker;
+
+impl JokerEffect for DuskJoker {
+    fn name(&self) -> &'static str {
+        "Dusk"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::CardScored]
+    }
+
+    fn apply(&self, card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if card.is_some() && context.run.hands_left == 0 {
+            return vec![ScoreOp::Retrigger];
+        }
+        Vec::new()
+    }
+}
+
+pub struct BlueJoker;
+
+impl JokerEffect for BlueJoker {
+    fn name(&self) -> &'static str {
+        "Blue Joker"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        let cards_in_deck = context.cards_in_deck();
+        vec![ScoreOp::AddChips(BigNum::from(2 * cards_in_deck as i64))]
+    }
+}
+
+pub struct JokerStencilJoker;
+
+impl JokerEffect for JokerStencilJoker {
+    fn name(&self) -> &'static str {
+        "Joker Stencil"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        let stencils = context
+            .all_jokers
+            .iter()
+            .filter(|joker| matches!(joker.joker, Joker::JokerStencil))
+            .count();
+        let factor = context.empty_joker_slots() + stencils;
+        if factor > 1 {
+            return vec![ScoreOp::MulMult(BigNum::from(factor as i64))];
+        }
+        Vec::new()
+    }
+}
+
+pub struct SupernovaJoker;
+
+impl JokerEffect for SupernovaJoker {
+    fn name(&self) -> &'static str {
+        "Supernova"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        let Some(hand_name) = context.best_hand_name else {
+            return Vec::new();
+        };
+        let times_played = context.run.times_played(hand_name);
+        vec![ScoreOp::AddMult(BigNum::from(times_played as i64))]
+    }
+}
+
+pub struct ObeliskJoker;
+
+impl JokerEffect for ObeliskJoker {
+    fn name(&self) -> &'static str {
+        "Obelisk"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        let streak = context.run.hands_since_most_played;
+        if streak > 0 {
+            return vec![ScoreOp::MulMult(BigNum::new(10 + 2 * streak as i64, 1))];
+        }
+        Vec::new()
+    }
+}
+
+pub struct CardSharpJoker;
+
+impl JokerEffect for CardSharpJoker {
+    fn name(&self) -> &'static str {
+        "Card Sharp"
+    }
+
+    fn phases(&self) -> &'static [Phase] {
+        &[Phase::Independent]
+    }
+
+    fn apply(&self, _card: Option<&Card>, context: &JokerContext) -> Vec<ScoreOp> {
+        if let Some(hand_name) = context.best_hand_name
+            && context.run.times_played_this_round(hand_name) > 1
+        {
+            return vec![ScoreOp::MulMult(BigNum::from(3))];
+        }
+        Vec::new()
     }
 }
 
@@ -1421,199 +1408,192 @@ pub struct JokerFactory;
 
 /// Creates a joker effect instance based on joker type.
 impl JokerFactory {
-    pub fn create_joker(joker_type: &ortalib::Joker) -> Box<dyn JokerEffect> {
+    /// Creates the effect of the joker in slot `index`, for jokers whose effect depends
+    /// on the rest of the lineup.
+    pub fn create_in_lineup(jokers: &[JokerCard], index: usize) -> Box<dyn JokerEffect> {
+        match jokers[index].joker {
+            Joker::Blueprint => Box::new(BlueprintJoker::in_lineup(jokers, index)),
+            joker_type => Self::create_joker(&joker_type),
+        }
+    }
+
+    pub fn create_joker(joker_type: &Joker) -> Box<dyn JokerEffect> {
         match joker_type {
-            ortalib::Joker::Joker => Box::new(BasicJoker),
-            ortalib::Joker::JollyJoker => Box::new(JollyJoker),
-            ortalib::Joker::ZanyJoker => Box::new(ZanyJoker),
-            ortalib::Joker::MadJoker => Box::new(MadJoker),
-            ortalib::Joker::CrazyJoker => Box::new(CrazyJoker),
-            ortalib::Joker::DrollJoker => Box::new(DrollJoker),
-            ortalib::Joker::SlyJoker => Box::new(SlyJoker),
-            ortalib::Joker::WilyJoker => Box::new(WilyJoker),
-            ortalib::Joker::CleverJoker => Box::new(CleverJoker),
-            ortalib::Joker::DeviousJoker => Box::new(DeviousJoker),
-            ortalib::Joker::CraftyJoker => Box::new(CraftyJoker),
-            ortalib::Joker::AbstractJoker => Box::new(AbstractJoker),
-            ortalib::Joker::RaisedFist => Box::new(RaisedFistJoker),
-            ortalib::Joker::Blackboard => Box::new(BlackboardJoker),
-            ortalib::Joker::Baron => Box::new(BaronJoker),
-            ortalib::Joker::GreedyJoker => Box::new(GreedyJoker),
-            ortalib::Joker::LustyJoker => Box::new(LustyJoker),
-            ortalib::Joker::WrathfulJoker => Box::new(WrathfulJoker),
-            ortalib::Joker::GluttonousJoker => Box::new(GluttonousJoker),
-            ortalib::Joker::Fibonacci => Box::new(FibonacciJoker),
-            ortalib::Joker::ScaryFace => Box::new(ScaryFaceJoker),
-            ortalib::Joker::EvenSteven => Box::new(EvenStevenJoker),
-            ortalib::Joker::OddTodd => Box::new(OddToddJoker),
-            ortalib::Joker::Photograph => Box::new(PhotographJoker),
-            ortalib::Joker::SmileyFace => Box::new(SmileyFaceJoker),
-            ortalib::Joker::FlowerPot => Box::new(FlowerPotJoker),
-            ortalib::Joker::FourFingers => Box::new(FourFingersJoker),
-            ortalib::Joker::Shortcut => Box::new(ShortcutJoker),
-            ortalib::Joker::Mime => Box::new(MimeJoker),
-            ortalib::Joker::Pareidolia => Box::new(PareidoliaJoker),
-            ortalib::Joker::Splash => Box::new(SplashJoker),
-            ortalib::Joker::SockAndBuskin => Box::new(SockAndBuskinJoker),
-            ortalib::Joker::SmearedJoker => Box::new(SmearedJoker),
-            ortalib::Joker::Blueprint => Box::new(BlueprintJoker),
-        }
-    }
-}
-
-/// Applies independent joker effects to chips and mult.
-pub fn apply_joker_effects(
-    jokers: &[JokerCard],
-    chips: &mut Chips,
-    mult: &mut Mult,
-    context: &JokerContext,
-) {
-    let mut processed_joker_types = std::collections::HashSet::new();
-
-    for joker in jokers {
-        let joker_id = get_joker_id(&joker.joker);
-
-        if !processed_joker_types.insert(joker_id) {
-            continue;
-        }
-
-        let joker_effect = JokerFactory::create_joker(&joker.joker);
-        if matches!(joker_effect.activation_type(), JokerActivation::Independent) {
-            joker_effect.apply(chips, mult, None, context);
+            Joker::Joker => Box::new(BasicJoker),
+            Joker::JollyJoker => Box::new(JollyJoker),
+            Joker::ZanyJoker => Box::new(ZanyJoker),
+            Joker::MadJoker => Box::new(MadJoker),
+            Joker::CrazyJoker => Box::new(CrazyJoker),
+            Joker::DrollJoker => Box::new(DrollJoker),
+            Joker::SlyJoker => Box::new(SlyJoker),
+            Joker::WilyJoker => Box::new(WilyJoker),
+            Joker::CleverJoker => Box::new(CleverJoker),
+            Joker::DeviousJoker => Box::new(DeviousJoker),
+            Joker::CraftyJoker => Box::new(CraftyJoker),
+            Joker::AbstractJoker => Box::new(AbstractJoker),
+            Joker::RaisedFist => Box::new(RaisedFistJoker),
+            Joker::Blackboard => Box::new(BlackboardJoker),
+            Joker::Baron => Box::new(BaronJoker),
+            Joker::GreedyJoker => Box::new(GreedyJoker),
+            Joker::LustyJoker => Box::new(LustyJoker),
+            Joker::WrathfulJoker => Box::new(WrathfulJoker),
+            Joker::GluttonousJoker => Box::new(GluttonousJoker),
+            Joker::Fibonacci => Box::new(FibonacciJoker),
+            Joker::ScaryFace => Box::new(ScaryFaceJoker),
+            Joker::EvenSteven => Box::new(EvenStevenJoker),
+            Joker::OddTodd => Box::new(OddToddJoker),
+            Joker::Photograph => Box::new(PhotographJoker),
+            Joker::SmileyFace => Box::new(SmileyFaceJoker),
+            Joker::FlowerPot => Box::new(FlowerPotJoker),
+            Joker::FourFingers => Box::new(FourFingersJoker),
+            Joker::Shortcut => Box::new(ShortcutJoker),
+            Joker::Mime => Box::new(MimeJoker),
+            Joker::Pareidolia => Box::new(PareidoliaJoker),
+            Joker::Splash => Box::new(SplashJoker),
+            Joker::SockAndBuskin => Box::new(SockAndBuskinJoker),
+            Joker::SmearedJoker => Box::new(SmearedJoker),
+            Joker::Blueprint => Box::new(BlueprintJoker::default()),
+            Joker::GoldenJoker => Box::new(GoldenJoker),
+            Joker::Bull => Box::new(BullJoker),
+            Joker::Bootstraps => Box::new(BootstrapsJoker),
+            Joker::BusinessCard => Box::new(BusinessCardJoker),
+            Joker::RoughGem => Box::new(RoughGemJoker),
+            Joker::Banner => Box::new(BannerJoker),
+            Joker::MysticSummit => Box::new(MysticSummitJoker),
+            Joker::Acrobat => Box::new(AcrobatJoker),
+            Joker::Dusk => Box::new(DuskJoker),
+            Joker::BlueJoker => Box::new(BlueJoker),
+            Joker::JokerStencil => Box::new(JokerStencilJoker),
+            Joker::Supernova => Box::new(SupernovaJoker),
+            Joker::Obelisk => Box::new(ObeliskJoker),
+            Joker::CardSharp => Box::new(CardSharpJoker),
         }
     }
 }
 
 /// Returns a unique ID for a joker type.
-pub fn get_joker_id(joker: &ortalib::Joker) -> u32 {
+pub fn get_joker_id(joker: &Joker) -> u32 {
     match joker {
-        ortalib::Joker::Joker => 1,
-        ortalib::Joker::JollyJoker => 2,
-        ortalib::Joker::ZanyJoker => 3,
-        ortalib::Joker::MadJoker => 4,
-        ortalib::Joker::CrazyJoker => 5,
-        ortalib::Joker::DrollJoker => 6,
-        ortalib::Joker::SlyJoker => 7,
-        ortalib::Joker::WilyJoker => 8,
-        ortalib::Joker::CleverJoker => 9,
-        ortalib::Joker::DeviousJoker => 10,
-        ortalib::Joker::CraftyJoker => 11,
-        ortalib::Joker::AbstractJoker => 12,
-        ortalib::Joker::RaisedFist => 13,
-        ortalib::Joker::Blackboard => 14,
-        ortalib::Joker::Baron => 15,
-        ortalib::Joker::GreedyJoker => 16,
-        ortalib::Joker::LustyJoker => 17,
-        ortalib::Joker::WrathfulJoker => 18,
-        ortalib::Joker::GluttonousJoker => 19,
-        ortalib::Joker::Fibonacci => 20,
-        ortalib::Joker::ScaryFace => 21,
-        ortalib::Joker::EvenSteven => 22,
-        ortalib::Joker::OddTodd => 23,
-        ortalib::Joker::Photograph => 24,
-        ortalib::Joker::SmileyFace => 25,
-        ortalib::Joker::FlowerPot => 26,
-        ortalib::Joker::FourFingers => 27,
-        ortalib::Joker::Shortcut => 28,
-        ortalib::Joker::Mime => 29,
-        ortalib::Joker::Pareidolia => 30,
-        ortalib::Joker::Splash => 31,
-        ortalib::Joker::SockAndBuskin => 32,
-        ortalib::Joker::SmearedJoker => 33,
-        ortalib::Joker::Blueprint => 34,
-    }
-}
-
-/// Applies OnHeld joker effects for a specific card.
-pub fn apply_onheld_joker_effects(
-    card: &Card,
-    jokers: &[JokerCard],
-    chips: &mut Chips,
-    mult: &mut Mult,
-    context: &JokerContext,
-) {
-    let mut processed_joker_types = std::collections::HashSet::new();
-
-    for joker in jokers {
-        let joker_id = get_joker_id(&joker.joker);
-
-        if !processed_joker_types.insert(joker_id) {
-            continue;
-        }
-
-        let joker_effect = JokerFactory::create_joker(&joker.joker);
-        if matches!(joker_effect.activation_type(), JokerActivation::OnHeld) {
-            joker_effect.apply(chips, mult, Some(card), context);
-        }
-    }
-}
-
-/// Applies OnScored joker effects for a specific card.
-pub fn apply_onscored_joker_effects(
-    card: &Card,
-    jokers: &[JokerCard],
-    chips: &mut Chips,
-    mult: &mut Mult,
-    context: &JokerContext,
-) {
-    for joker in jokers {
-        let joker_effect = JokerFactory::create_joker(&joker.joker);
-        if matches!(joker_effect.activation_type(), JokerActivation::OnScored) {
-            joker_effect.apply(chips, mult, Some(card), context);
-        }
-    }
-}
-
-/// Applies retriggerable joker effects for cards in hand.
-pub fn apply_jokers_retrigger(
-    jokers: &[JokerCard],
-    cards_in_hand: &[Card],
-    chips: &mut Chips,
-    mult: &mut Mult,
-    context: &JokerContext,
-) {
-    if cards_in_hand.is_empty() {
-        return;
-    }
-
-    for card in cards_in_hand {
-        let mut processed_joker_types = std::collections::HashSet::new();
-
-        for joker_card in jokers {
-            if matches!(joker_card.joker, ortalib::Joker::Mime) {
-                continue;
-            }
+        Joker::Joker => 1,
+        Joker::JollyJoker => 2,
+        Joker::ZanyJoker => 3,
+        Joker::MadJoker => 4,
+        Joker::CrazyJoker => 5,
+        Joker::DrollJoker => 6,
+        Joker::SlyJoker => 7,
+        Joker::WilyJoker => 8,
+        Joker::CleverJoker => 9,
+        Joker::DeviousJoker => 10,
+        Joker::CraftyJoker => 11,
+        Joker::AbstractJoker => 12,
+        Joker::RaisedFist => 13,
+        Joker::Blackboard => 14,
+        Joker::Baron => 15,
+        Joker::GreedyJoker => 16,
+        Joker::LustyJoker => 17,
+        Joker::WrathfulJoker => 18,
+        Joker::GluttonousJoker => 19,
+        Joker::Fibonacci => 20,
+        Joker::ScaryFace => 21,
+        Joker::EvenSteven => 22,
+        Joker::OddTodd => 23,
+        Joker::Photograph => 24,
+        Joker::SmileyFace => 25,
+        Joker::FlowerPot => 26,
+        Joker::FourFingers => 27,
+        Joker::Shortcut => 28,
+        Joker::Mime => 29,
+        Joker::Pareidolia => 30,
+        Joker::Splash => 31,
+        Joker::SockAndBuskin => 32,
+        Joker::SmearedJoker => 33,
+        Joker::Blueprint => 34,
+        Joker::GoldenJoker => 35,
+        Joker::Bull => 36,
+        Joker::Bootstraps => 37,
+        Joker::BusinessCard => 38,
+        Joker::RoughGem => 39,
+        Joker::Banner => 40,
+        Joker::MysticSummit => 41,
+        Joker::Acrobat => 42,
+        Joker::Dusk => 43,
+        Joker::BlueJoker => 44,
+        Joker::JokerStencil => 45,
+        Joker::Supernova => 46,
+        Joker::Obelisk => 47,
+        Joker::CardSharp => 48,
+    }
+}
 
-            let joker_id = get_joker_id(&joker_card.joker);
+/// The jokers taking part in a hand, each with its effect, in slot order.
+pub struct JokerHooks {
+    jokers: Vec<(JokerCard, Box<dyn JokerEffect>)>,
+}
 
-            if !processed_joker_types.insert(joker_id) {
-                continue;
-            }
+impl JokerHooks {
+    pub fn new(jokers: &[JokerCard]) -> Self {
+        JokerHooks {
+            jokers: jokers
+                .iter()
+                .enumerate()
+                .map(|(index, joker)| (*joker, JokerFactory::create_in_lineup(jokers, index)))
+                .collect(),
+        }
+    }
 
-            let effect = JokerFactory::create_joker(&joker_card.joker);
+    /// Returns the joker effects in slot order.
+    pub fn effects(&self) -> impl Iterator<Item = &dyn JokerEffect> {
+        self.jokers.iter().map(|(_, effect)| effect.as_ref())
+    }
 
-            if matches!(effect.activation_type(), JokerActivation::OnHeld) {
-                effect.apply(chips, mult, Some(card), context);
-            }
-        }
+    /// Runs an event through every joker subscribed to its phase, returning their
+    /// operations in the order they apply.
+    ///
+    /// Jokers go in slot order. In the independent phase each joker's Foil or Holographic
+    /// edition applies just before it and its Polychrome edition just after, whether or not
+    /// the joker subscribes.
+    pub fn dispatch(&self, event: &mut JokerEvent, context: &JokerContext) -> Vec<SourcedOp> {
+        let mut ops = Vec::new();
+        for (joker, effect) in &self.jokers {
+            let source = OpSource::Joker(*joker);
+            let edition = joker.edition.filter(|_| event.phase == Phase::Independent);
 
-        processed_joker_types.clear();
-        for joker_card in jokers {
-            if !matches!(joker_card.joker, ortalib::Joker::Mime) {
-                continue;
+            if let Some(edition) = edition
+                && matches!(edition, Edition::Foil | Edition::Holographic)
+            {
+                ops.extend(SourcedOp::all(
+                    source,
+                    modifiers::apply_edition_effect(&edition),
+                ));
             }
 
-            let joker_id = get_joker_id(&joker_card.joker);
-
-            if !processed_joker_types.insert(joker_id) {
-                continue;
+            if effect.handles(event.phase) {
+                ops.extend(SourcedOp::all(source, effect.on_phase(event, context)));
             }
 
-            let effect = JokerFactory::create_joker(&joker_card.joker);
+            if let Some(edition) = edition
+                && matches!(edition, Edition::Polychrome)
+            {
+                ops.extend(SourcedOp::all(
+                    source,
+                    modifiers::apply_edition_effect(&edition),
+                ));
+            }
+        }
+        ops
+    }
 
-            if matches!(effect.activation_type(), JokerActivation::OnHeld) {
-                effect.apply(chips, mult, Some(card), context);
+    /// Runs an event through the subscribed jokers again for a retriggered card, leaving
+    /// out any further retriggers.
+    pub fn replay(&self, event: &mut JokerEvent, context: &JokerContext) -> Vec<SourcedOp> {
+        let mut ops = Vec::new();
+        for (joker, effect) in &self.jokers {
+            if effect.handles(event.phase) {
+                let source = OpSource::Joker(*joker);
+                ops.extend(SourcedOp::all(source, effect.on_phase(event, context)));
             }
         }
+        ops.retain(|sourced| sourced.op != ScoreOp::Retrigger);
+        ops
     }
 }
@@ -1,50 +1,361 @@
+pub mod advisor;
+pub mod attribution;
+pub mod bignum;
+pub mod blind;
+pub mod consumable;
+pub mod deck;
+pub mod diff;
+pub mod economy;
+pub mod input;
 pub mod joker;
+pub mod model;
 pub mod modifiers;
+pub mod ops;
+pub mod optimizer;
 pub mod pokerhand;
+pub mod rng;
+pub mod schema;
 pub mod score;
+pub mod shop;
+pub mod spectral;
+pub mod stake;
+pub mod tarot;
+pub mod validate;
+pub mod voucher;
 
 use std::{
     error::Error,
-    fs::File,
-    io::{Read, stdin},
+    fs::{self, File},
+    io::{self, Read, stdin},
     path::{Path, PathBuf},
+    process::ExitCode,
 };
 
-use clap::Parser;
-use ortalib::Round;
-use score::ScoreManager;
+use advisor::{AdvisorInput, AdvisorOptions};
+use bignum::BigNum;
+use blind::BlindInput;
+use clap::{Parser, Subcommand, ValueEnum};
+use input::{InputError, InputFormat};
+use model::Round;
+use score::{ScoreError, ScoreManager};
+use serde::de::DeserializeOwned;
+use shop::ShopInput;
+use spectral::SpectralUse;
+use stake::{BlindKind, BlindTarget, Stake};
+use tarot::TarotUse;
 
-#[derive(Parser)]
+/// Exit status when the input describes an impossible round or move.
+const EXIT_INVALID_INPUT: u8 = 1;
+/// Exit status when an input file cannot be read. Usage errors exit with 2.
+const EXIT_IO: u8 = 3;
+/// Exit status when an input file does not parse.
+const EXIT_PARSE: u8 = 4;
+/// Exit status when the engine cannot score a well-formed round.
+const EXIT_ENGINE: u8 = 5;
 
+#[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Opts {
-    file: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(required = true)]
+    file: Option<PathBuf>,
 
     #[arg(long)]
     explain: bool,
+
+    /// Search for the joker order that maximizes the score.
+    #[arg(long)]
+    optimize_jokers: bool,
+
+    /// Report how much of the score each joker and card is responsible for.
+    #[arg(long)]
+    attribution: bool,
+
+    /// Chip requirement to compare the score against.
+    #[arg(long, conflicts_with = "ante", value_parser = parse_target)]
+    target: Option<BigNum>,
+
+    /// Compare the score against this ante's blind requirement.
+    #[arg(long)]
+    ante: Option<u32>,
+
+    #[arg(long, value_enum, default_value_t = Stake::White, requires = "ante")]
+    stake: Stake,
+
+    #[arg(long, value_enum, default_value_t = BlindKind::Small, requires = "ante")]
+    blind: BlindKind,
+
+    /// How the final score is printed.
+    #[arg(long, value_enum, default_value_t = Notation::Exact)]
+    notation: Notation,
+
+    /// Format of the input files. Defaults to their extension, then YAML.
+    #[arg(long, value_enum, global = true)]
+    input_format: Option<InputFormat>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Notation {
+    /// Every digit of the score.
+    Exact,
+    /// Scientific notation for large scores, e.g. `1.23e45`.
+    Game,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+#[derive(Subcommand)]
+enum Command {
+    /// Recommend which cards to discard from the current hand.
+    Advise {
+        file: PathBuf,
+
+        /// Number of simulated draws per possible discard.
+        #[arg(long, default_value_t = 200)]
+        samples: usize,
+
+        /// Seed for the simulated draws.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+
+    /// Score two rounds and explain how the second differs from the first.
+    Diff { before: PathBuf, after: PathBuf },
+
+    /// Print the JSON Schema of the round input format.
+    Schema,
+
+    /// Check a round for problems without scoring it.
+    Validate { file: PathBuf },
+
+    /// Play a sequence of hands and discards against a blind's target score.
+    Blind { file: PathBuf },
+
+    /// Buy, sell and reroll in a seeded shop between blinds.
+    Shop { file: PathBuf },
+
+    /// Use tarot cards on a round and print the resulting round.
+    Tarot {
+        file: PathBuf,
+
+        /// A tarot and the cards to use it on, e.g. `--use "The Empress:0,1"`. Indices
+        /// count through the played cards, then the cards held in hand.
+        #[arg(long = "use", value_name = "TAROT:INDICES", required = true)]
+        uses: Vec<TarotUse>,
+
+        /// Write the round here instead of standard output.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Use spectral cards on a round and print the resulting round.
+    Spectral {
+        file: PathBuf,
+
+        /// A spectral card and the cards to use it on, e.g. `--use Cryptid:2` or
+        /// `--use Sigil`. Random choices are drawn from the round's seed.
+        #[arg(long = "use", value_name = "SPECTRAL[:INDICES]", required = true)]
+        uses: Vec<SpectralUse>,
+
+        /// Write the round here instead of standard output.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
     let opts = Opts::parse();
-    let round = parse_round(&opts)?;
+    match run(&opts) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::from(exit_code(error.as_ref()))
+        }
+    }
+}
 
-    let (chips, mult, explanation) = ScoreManager::score_with_explanation(&round);
+/// Picks the exit status for an error, looking through the errors it wraps.
+fn exit_code(error: &(dyn Error + 'static)) -> u8 {
+    let mut current = Some(error);
+    while let Some(error) = current {
+        if let Some(error) = error.downcast_ref::<ScoreError>() {
+            return if error.is_engine_error() {
+                EXIT_ENGINE
+            } else {
+                EXIT_INVALID_INPUT
+            };
+        }
+        if error.is::<io::Error>() {
+            return EXIT_IO;
+        }
+        if error.is::<InputError>() {
+            return EXIT_PARSE;
+        }
+        current = error.source();
+    }
+    EXIT_INVALID_INPUT
+}
+
+fn run(opts: &Opts) -> Result<ExitCode, Box<dyn Error>> {
+    if let Some(Command::Advise {
+        file,
+        samples,
+        seed,
+    }) = &opts.command
+    {
+        let input: AdvisorInput = parse_file(file, opts)?;
+        let options = AdvisorOptions {
+            samples: *samples,
+            seed: *seed,
+        };
+        println!("{}", advisor::advise_discard(&input, &options)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(Command::Diff { before, after }) = &opts.command {
+        let before: Round = parse_file(before, opts)?;
+        let after: Round = parse_file(after, opts)?;
+        before.check_joker_slots()?;
+        after.check_joker_slots()?;
+        println!("{}", diff::diff_rounds(&before, &after)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(Command::Schema) = &opts.command {
+        println!("{}", serde_json::to_string_pretty(&schema::round_schema())?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(Command::Validate { file }) = &opts.command {
+        let report = validate::validate_round(&read_input(file)?, input_format(file, opts));
+        println!("{}", report);
+        if report.has_errors() {
+            return Ok(ExitCode::from(EXIT_INVALID_INPUT));
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(Command::Blind { file }) = &opts.command {
+        let input: BlindInput = parse_file(file, opts)?;
+        println!("{}", blind::simulate_blind(&input)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(Command::Shop { file }) = &opts.command {
+        let input: ShopInput = parse_file(file, opts)?;
+        println!("{}", shop::simulate_shop(&input)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(Command::Tarot { file, uses, output }) = &opts.command {
+        let mut round: Round = parse_file(file, opts)?;
+        tarot::apply_tarots(&mut round, uses)?;
+        write_round(&round, output.as_deref())?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(Command::Spectral { file, uses, output }) = &opts.command {
+        let mut round: Round = parse_file(file, opts)?;
+        spectral::apply_spectrals(&mut round, uses)?;
+        write_round(&round, output.as_deref())?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let round = parse_round(opts)?;
+    round.check_joker_slots()?;
+
+    if opts.optimize_jokers {
+        println!("{}", optimizer::optimize_joker_order(&round)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if opts.attribution {
+        println!("{}", attribution::attribute_score(&round)?);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let blind = match opts.ante {
+        Some(ante) => {
+            let mut blind = BlindTarget::new(ante, opts.stake, opts.blind)?;
+            if let Some(variant) = round.run.deck_variant {
+                blind.target *= &BigNum::from(variant.blind_multiplier());
+            }
+            Some(blind)
+        }
+        None => None,
+    };
+
+    let mut manager = ScoreManager::from_round(&round);
+    let score = manager.calculate_score()?;
+    manager.end_round();
 
     if opts.explain {
-        println!("{}", explanation);
+        println!("{}", manager.explanation(&score));
     } else {
-        println!("{}", (chips * mult).floor());
+        match opts.notation {
+            Notation::Exact => println!("{}", score),
+            Notation::Game => println!("{}", score.to_game_string()),
+        }
     }
-    Ok(())
+
+    if let Some(target) = &opts.target {
+        println!("{}", stake::describe_margin(&score, target));
+    } else if let Some(blind) = blind {
+        println!(
+            "{}: {}",
+            blind,
+            stake::describe_margin(&score, &blind.target)
+        );
+    }
+
+    let earnings = manager.earnings();
+    if earnings.starting_money != 0 || earnings.total() != 0 {
+        println!("{}", earnings);
+    }
+    if let Some(lineup) = model::lineup_after_round(manager.lineup()) {
+        println!("{}", lineup);
+    }
+    Ok(ExitCode::SUCCESS)
 }
 
-fn parse_round(opts: &Opts) -> Result<Round, Box<dyn Error>> {
+fn read_input(file: &Path) -> Result<String, Box<dyn Error>> {
     let mut input = String::new();
-    if opts.file == Path::new("-") {
+    if file == Path::new("-") {
         stdin().read_to_string(&mut input)?;
     } else {
-        File::open(&opts.file)?.read_to_string(&mut input)?;
+        File::open(file)?.read_to_string(&mut input)?;
+    }
+    Ok(input)
+}
+
+/// Writes a round as YAML to a file, or to standard output.
+fn write_round(round: &Round, output: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let yaml = serde_yaml::to_string(round)?;
+    match output {
+        Some(path) => fs::write(path, yaml)?,
+        None => print!("{}", yaml),
     }
+    Ok(())
+}
+
+fn parse_round(opts: &Opts) -> Result<Round, Box<dyn Error>> {
+    let file = opts.file.as_deref().unwrap_or(Path::new("-"));
+    parse_file(file, opts)
+}
+
+/// Reads and parses an input file in the format given on the command line or by its
+/// extension.
+fn parse_file<T: DeserializeOwned>(file: &Path, opts: &Opts) -> Result<T, Box<dyn Error>> {
+    let input = read_input(file)?;
+    Ok(input::parse(&input, input_format(file, opts))?)
+}
+
+fn input_format(file: &Path, opts: &Opts) -> InputFormat {
+    opts.input_format
+        .unwrap_or_else(|| InputFormat::from_path(file))
+}
 
-    let round = serde_yaml::from_str(&input)?;
-    Ok(round)
+/// Parses `--target` exactly, as a decimal such as `300` or `1.2e20`.
+fn parse_target(text: &str) -> Result<BigNum, String> {
+    text.parse()
 }
@@ -0,0 +1,846 @@
+//! Round, card and joker types.
+//!
+//! These mirror `ortalib`'s types and parse the same text format, but also cover the
+//! parts of the game `ortalib` skips, such as Gold cards, seals and more jokers.
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub use ortalib::{Rank, Suit};
+
+use crate::deck::{ABANDONED_DECK_SIZE, STANDARD_DECK_SIZE};
+
+/// The inputs of one scoring round, plus the run state it is played in.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
+pub struct Round {
+    pub cards_played: Vec<Card>,
+
+    #[serde(default)]
+    pub cards_held_in_hand: Vec<Card>,
+
+    #[serde(default)]
+    pub jokers: Vec<JokerCard>,
+
+    #[serde(flatten)]
+    pub run: RunState,
+}
+
+impl Round {
+    pub fn new(
+        cards_played: Vec<Card>,
+        cards_held_in_hand: Vec<Card>,
+        jokers: Vec<JokerCard>,
+    ) -> Self {
+        Round {
+            cards_played,
+            cards_held_in_hand,
+            jokers,
+            run: RunState::default(),
+        }
+    }
+
+    /// Checks that the jokers fit in the run's joker slots.
+    pub fn check_joker_slots(&self) -> Result<(), JokerSlotError> {
+        check_joker_slots(&self.jokers, self.run.joker_slot_count())
+    }
+}
+
+/// Number of joker slots a lineup fills; Negative jokers bring their own slot.
+pub fn occupied_joker_slots(jokers: &[JokerCard]) -> usize {
+    jokers
+        .iter()
+        .filter(|joker| joker.edition != Some(Edition::Negative))
+        .count()
+}
+
+/// Lists the jokers left after a round, marking debuffed ones, or returns `None` when no
+/// joker is Perishable and so nothing changed.
+pub fn lineup_after_round(jokers: &[JokerCard]) -> Option<String> {
+    if jokers
+        .iter()
+        .all(|joker| joker.stickers.perishable.is_none())
+    {
+        return None;
+    }
+
+    let jokers: Vec<String> = jokers
+        .iter()
+        .map(|joker| {
+            if joker.is_debuffed() {
+                format!("{} (debuffed)", joker)
+            } else {
+                joker.to_string()
+            }
+        })
+        .collect();
+    Some(format!("Jokers after round: {}", jokers.join(", ")))
+}
+
+/// Checks that a lineup fits in the given number of joker slots.
+pub fn check_joker_slots(jokers: &[JokerCard], slots: usize) -> Result<(), JokerSlotError> {
+    let occupied = occupied_joker_slots(jokers);
+    if occupied > slots {
+        return Err(JokerSlotError { occupied, slots });
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+pub struct JokerSlotError {
+    pub occupied: usize,
+    pub slots: usize,
+}
+
+impl Display for JokerSlotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} non-Negative jokers do not fit in {} joker slots",
+            self.occupied, self.slots
+        )
+    }
+}
+
+impl std::error::Error for JokerSlotError {}
+
+/// State of the run that outlives a single hand.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
+pub struct RunState {
+    /// Dollars held when the hand is played.
+    #[serde(default)]
+    pub money: i64,
+
+    /// Seed for chance-based effects such as Business Card.
+    #[serde(default)]
+    pub seed: u64,
+
+    /// Hands left to play in the round after this one.
+    #[serde(default = "default_hands_left")]
+    pub hands_left: u32,
+
+    #[serde(default = "default_discards_left")]
+    pub discards_left: u32,
+
+    /// Every card the deck owns. Defaults to a standard 52 card deck.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deck: Option<Vec<Card>>,
+
+    /// Cards left in the draw pile. Defaults to the deck minus the cards in play.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cards_in_deck: Option<usize>,
+
+    /// Times each poker hand has been played this run, keyed by hand name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub hand_play_counts: HashMap<String, u32>,
+
+    /// Times each poker hand has been played this round, keyed by hand name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub round_hand_play_counts: HashMap<String, u32>,
+
+    /// Consecutive hands played without playing the most played poker hand.
+    #[serde(default)]
+    pub hands_since_most_played: u32,
+
+    #[serde(default = "default_joker_slots")]
+    pub joker_slots: usize,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deck_variant: Option<DeckVariant>,
+}
+
+impl RunState {
+    /// Number of cards the deck owns.
+    pub fn deck_size(&self) -> usize {
+        match (&self.deck, self.deck_variant) {
+            (Some(deck), _) => deck.len(),
+            (None, Some(DeckVariant::Abandoned)) => ABANDONED_DECK_SIZE,
+            (None, _) => STANDARD_DECK_SIZE,
+        }
+    }
+
+    /// Joker slots available after the deck variant's penalty, not counting the extra
+    /// slots of Negative jokers.
+    pub fn joker_slot_count(&self) -> usize {
+        let penalty = self
+            .deck_variant
+            .map_or(0, |variant| variant.joker_slot_penalty());
+        self.joker_slots.saturating_sub(penalty)
+    }
+
+    pub fn is_plasma(&self) -> bool {
+        self.deck_variant == Some(DeckVariant::Plasma)
+    }
+
+    /// Times the named poker hand has been played this run.
+    pub fn times_played(&self, hand_name: &str) -> u32 {
+        self.hand_play_counts.get(hand_name).copied().unwrap_or(0)
+    }
+
+    /// Times the named poker hand has been played this round.
+    pub fn times_played_this_round(&self, hand_name: &str) -> u32 {
+        self.round_hand_play_counts
+            .get(hand_name)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Counts a play of the named poker hand, as the game does before scoring it.
+    pub fn record_hand_played(&mut self, hand_name: &str) {
+        let played = self
+            .hand_play_counts
+            .entry(hand_name.to_string())
+            .or_insert(0);
+        *played += 1;
+        let played = *played;
+
+        *self
+            .round_hand_play_counts
+            .entry(hand_name.to_string())
+            .or_insert(0) += 1;
+
+        let most_played = self
+            .hand_play_counts
+            .iter()
+            .all(|(name, &count)| name == hand_name || count < played);
+        if most_played {
+            self.hands_since_most_played = 0;
+        } else {
+            self.hands_since_most_played += 1;
+        }
+    }
+}
+
+impl Default for RunState {
+    fn default() -> Self {
+        RunState {
+            money: 0,
+            seed: 0,
+            hands_left: default_hands_left(),
+            discards_left: default_discards_left(),
+            deck: None,
+            cards_in_deck: None,
+            hand_play_counts: HashMap::new(),
+            round_hand_play_counts: HashMap::new(),
+            hands_since_most_played: 0,
+            joker_slots: default_joker_slots(),
+            deck_variant: None,
+        }
+    }
+}
+
+fn default_hands_left() -> u32 {
+    3
+}
+
+fn default_discards_left() -> u32 {
+    3
+}
+
+fn default_joker_slots() -> usize {
+    5
+}
+
+/// One playing card.
+///
+/// Like `ortalib::Card`, a card only compares equal to itself and its copies.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Card {
+    pub rank: Rank,
+    pub suit: Suit,
+    pub enhancement: Option<Enhancement>,
+    pub edition: Option<Edition>,
+    pub seal: Option<Seal>,
+    unique_index: usize,
+}
+
+impl Card {
+    pub fn new(
+        rank: Rank,
+        suit: Suit,
+        enhancement: Option<Enhancement>,
+        edition: Option<Edition>,
+    ) -> Self {
+        static UNIQUE_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+        Card {
+            rank,
+            suit,
+            enhancement,
+            edition,
+            seal: None,
+            unique_index: UNIQUE_INDEX.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+
+    pub fn with_seal(mut self, seal: Option<Seal>) -> Self {
+        self.seal = seal;
+        self
+    }
+
+    /// Returns a new card with the same rank, suit, enhancement, edition and seal.
+    pub fn duplicate(&self) -> Self {
+        Card::new(self.rank, self.suit, self.enhancement, self.edition).with_seal(self.seal)
+    }
+
+    /// Stone cards have no rank or suit.
+    pub fn is_stone(&self) -> bool {
+        matches!(self.enhancement, Some(Enhancement::Stone))
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.rank, self.suit)?;
+
+        if let Some(enhancement) = self.enhancement {
+            write!(f, " {enhancement}")?;
+        }
+
+        if let Some(edition) = self.edition {
+            write!(f, " {edition}")?;
+        }
+
+        if let Some(seal) = self.seal {
+            write!(f, " {seal}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Card {
+    type Err = String;
+
+    /// Parses `A♠`, optionally followed by an enhancement, an edition and a seal,
+    /// e.g. `K♥ Gold Foil Gold Seal`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_ascii_whitespace().peekable();
+        let rank_suit = parts.next().ok_or("Cannot parse empty string")?;
+
+        // Suit is always exactly 1 char.
+        let mut reversed = rank_suit.chars().rev();
+        let suit_str = reversed
+            .next()
+            .ok_or_else(|| format!("Card `{s}` missing rank / suit"))?
+            .to_string();
+        let rank_str: String = reversed.rev().collect();
+
+        let rank = rank_str
+            .parse()
+            .map_err(|err| format!("Card `{s}` has invalid rank: {err}"))?;
+        let suit = suit_str
+            .parse()
+            .map_err(|err| format!("Card `{s}` has invalid suit: {err}"))?;
+
+        let mut card = Card::new(rank, suit, None, None);
+        while let Some(part) = parts.next() {
+            if parts.peek() == Some(&"Seal") {
+                parts.next();
+                let seal = format!("{part} Seal")
+                    .parse()
+                    .map_err(|err| format!("Card `{s}` has invalid seal: {err}"))?;
+                set_once(&mut card.seal, seal, s, "seal")?;
+            } else if let Ok(enhancement) = part.parse() {
+                set_once(&mut card.enhancement, enhancement, s, "enhancement")?;
+            } else if let Ok(edition) = part.parse() {
+                set_once(&mut card.edition, edition, s, "edition")?;
+            } else {
+                return Err(format!(
+                    "Card `{s}` has invalid enhancement / edition / seal: `{part}`"
+                ));
+            }
+        }
+
+        Ok(card)
+    }
+}
+
+/// Stores a parsed card property, rejecting a second value for the same property.
+fn set_once<T>(slot: &mut Option<T>, value: T, card: &str, property: &str) -> Result<(), String> {
+    if slot.is_some() {
+        return Err(format!("Card `{card}` has more than one {property}"));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// One joker held by the player, with its possible edition.
+///
+/// Like `ortalib::JokerCard`, a joker only compares equal to itself and its copies.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct JokerCard {
+    pub joker: Joker,
+    pub edition: Option<Edition>,
+    pub stickers: Stickers,
+    unique_index: usize,
+}
+
+impl JokerCard {
+    pub fn new(joker: Joker, edition: Option<Edition>) -> Self {
+        static UNIQUE_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+        JokerCard {
+            joker,
+            edition,
+            stickers: Stickers::default(),
+            unique_index: UNIQUE_INDEX.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+
+    pub fn with_stickers(mut self, stickers: Stickers) -> Self {
+        self.stickers = stickers;
+        self
+    }
+
+    /// Debuffed jokers keep their slot but contribute nothing to scoring.
+    pub fn is_debuffed(&self) -> bool {
+        self.stickers.perishable == Some(0)
+    }
+}
+
+impl Display for JokerCard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.joker)?;
+
+        if let Some(edition) = self.edition {
+            write!(f, " {edition}")?;
+        }
+
+        write!(f, "{}", self.stickers)
+    }
+}
+
+impl Debug for JokerCard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for JokerCard {
+    type Err = String;
+
+    /// Parses a joker name, optionally followed by an edition and stickers, e.g.
+    /// `Greedy Joker Foil Eternal` or `Mime Perishable 2 Rental`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rest, stickers) = Stickers::strip_from(s)?;
+        let mut joker_str = rest;
+        let mut edition = None;
+
+        for possible_edition in Edition::ALL {
+            if let Some(leftover) = rest.strip_suffix(possible_edition.name()) {
+                joker_str = leftover.trim();
+                edition = Some(*possible_edition);
+                break;
+            }
+        }
+
+        let joker = joker_str
+            .parse()
+            .map_err(|err| format!("Invalid JokerCard `{s}`: {err}"))?;
+
+        Ok(JokerCard::new(joker, edition).with_stickers(stickers))
+    }
+}
+
+/// Rounds a Perishable joker lasts when no count is given.
+pub const DEFAULT_PERISHABLE_ROUNDS: u32 = 5;
+
+/// Rent charged for each Rental joker at the end of every round.
+pub const RENTAL_COST: i64 = 3;
+
+/// Stickers placed on jokers at higher stakes.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Stickers {
+    /// Cannot be sold or destroyed.
+    pub eternal: bool,
+    /// Rounds left before the joker is debuffed.
+    pub perishable: Option<u32>,
+    /// Costs `RENTAL_COST` at the end of every round.
+    pub rental: bool,
+}
+
+impl Stickers {
+    /// Counts a Perishable sticker down by one finished round, stopping at 0.
+    pub fn count_down(&mut self) {
+        if let Some(rounds) = &mut self.perishable {
+            *rounds = rounds.saturating_sub(1);
+        }
+    }
+
+    /// Splits trailing `Eternal`, `Perishable [N]` and `Rental` words off a joker.
+    fn strip_from(s: &str) -> Result<(&str, Stickers), String> {
+        let mut stickers = Stickers::default();
+        let mut rest = s.trim_end();
+
+        loop {
+            let (head, last) = rest.rsplit_once(' ').unwrap_or(("", rest));
+            let head = head.trim_end();
+            match last {
+                "Eternal" if !stickers.eternal => stickers.eternal = true,
+                "Rental" if !stickers.rental => stickers.rental = true,
+                "Perishable" if stickers.perishable.is_none() => {
+                    stickers.perishable = Some(DEFAULT_PERISHABLE_ROUNDS)
+                }
+                _ => {
+                    let Some(prefix) = head.strip_suffix("Perishable") else {
+                        break;
+                    };
+                    let Ok(rounds) = last.parse() else {
+                        break;
+                    };
+                    if stickers.perishable.is_some() {
+                        return Err(format!("JokerCard `{s}` has more than one Perishable"));
+                    }
+                    stickers.perishable = Some(rounds);
+                    rest = prefix.trim_end();
+                    continue;
+                }
+            }
+            rest = head;
+        }
+
+        Ok((rest, stickers))
+    }
+}
+
+impl Display for Stickers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.eternal {
+            write!(f, " Eternal")?;
+        }
+        if let Some(rounds) = self.perishable {
+            write!(f, " Perishable {rounds}")?;
+        }
+        if self.rental {
+            write!(f, " Rental")?;
+        }
+        Ok(())
+    }
+}
+
+/// Defines the `Joker` enum together with its display names.
+macro_rules! jokers {
+    ($($(#[$doc:meta])* $variant:ident => $name:literal,)*) => {
+        /// The joker effects the engine supports.
+        #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub enum Joker {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl Joker {
+            /// Every joker, in collection order.
+            pub const ALL: &'static [Joker] = &[$(Joker::$variant,)*];
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Joker::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+jokers! {
+    Joker => "Joker",
+    JollyJoker => "Jolly Joker",
+    ZanyJoker => "Zany Joker",
+    MadJoker => "Mad Joker",
+    CrazyJoker => "Crazy Joker",
+    DrollJoker => "Droll Joker",
+    SlyJoker => "Sly Joker",
+    WilyJoker => "Wily Joker",
+    CleverJoker => "Clever Joker",
+    DeviousJoker => "Devious Joker",
+    CraftyJoker => "Crafty Joker",
+    AbstractJoker => "Abstract Joker",
+    RaisedFist => "Raised Fist",
+    Blackboard => "Blackboard",
+    Baron => "Baron",
+    GreedyJoker => "Greedy Joker",
+    LustyJoker => "Lusty Joker",
+    WrathfulJoker => "Wrathful Joker",
+    GluttonousJoker => "Gluttonous Joker",
+    Fibonacci => "Fibonacci",
+    ScaryFace => "Scary Face",
+    EvenSteven => "Even Steven",
+    OddTodd => "Odd Todd",
+    Photograph => "Photograph",
+    SmileyFace => "Smiley Face",
+    FlowerPot => "Flower Pot",
+    FourFingers => "Four Fingers",
+    Shortcut => "Shortcut",
+    Mime => "Mime",
+    Pareidolia => "Pareidolia",
+    Splash => "Splash",
+    SockAndBuskin => "Sock And Buskin",
+    SmearedJoker => "Smeared Joker",
+    Blueprint => "Blueprint",
+    /// Earn $4 at end of round.
+    GoldenJoker => "Golden Joker",
+    /// +2 Chips for each $1 held.
+    Bull => "Bull",
+    /// +2 Mult for every $5 held.
+    Bootstraps => "Bootstraps",
+    /// Scored face cards have a 1 in 2 chance to give $2.
+    BusinessCard => "Business Card",
+    /// Scored Diamonds give $1.
+    RoughGem => "Rough Gem",
+    /// +30 Chips for each remaining discard.
+    Banner => "Banner",
+    /// +15 Mult when no discards remain.
+    MysticSummit => "Mystic Summit",
+    /// x3 Mult on the final hand of the round.
+    Acrobat => "Acrobat",
+    /// Retrigger all played cards in the final hand of the round.
+    Dusk => "Dusk",
+    /// +2 Chips for each card remaining in the deck.
+    BlueJoker => "Blue Joker",
+    /// x1 Mult for each empty joker slot, counting Joker Stencil itself as empty.
+    JokerStencil => "Joker Stencil",
+    /// Adds the number of times the played poker hand has been played this run to Mult.
+    Supernova => "Supernova",
+    /// Gains x0.2 Mult per consecutive hand played without playing the most played poker hand.
+    Obelisk => "Obelisk",
+    /// x3 Mult if the played poker hand has already been played this round.
+    CardSharp => "Card Sharp",
+}
+
+/// Enhancements change how a card scores.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Enhancement {
+    /// +30 Chips.
+    Bonus,
+    /// +4 Mult.
+    Mult,
+    /// Counts as every suit.
+    Wild,
+    /// x2 Mult.
+    Glass,
+    /// x1.5 Mult while held in hand.
+    Steel,
+    /// $3 if held in hand at end of round.
+    Gold,
+    /// 1 in 5 chance of +20 Mult and 1 in 15 chance of $20 when scored.
+    Lucky,
+    /// +50 Chips; has no rank or suit and always scores.
+    Stone,
+}
+
+impl Enhancement {
+    pub const ALL: &'static [Enhancement] = &[
+        Enhancement::Bonus,
+        Enhancement::Mult,
+        Enhancement::Wild,
+        Enhancement::Glass,
+        Enhancement::Steel,
+        Enhancement::Gold,
+        Enhancement::Lucky,
+        Enhancement::Stone,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Enhancement::Bonus => "Bonus",
+            Enhancement::Mult => "Mult",
+            Enhancement::Wild => "Wild",
+            Enhancement::Glass => "Glass",
+            Enhancement::Steel => "Steel",
+            Enhancement::Gold => "Gold",
+            Enhancement::Lucky => "Lucky",
+            Enhancement::Stone => "Stone",
+        }
+    }
+}
+
+/// Editions give cards and jokers a bonus when they score.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Edition {
+    /// +50 Chips.
+    Foil,
+    /// +10 Mult.
+    Holographic,
+    /// x1.5 Mult.
+    Polychrome,
+    /// +1 joker slot on a joker, +1 hand size on a playing card.
+    Negative,
+}
+
+impl Edition {
+    pub const ALL: &'static [Edition] = &[
+        Edition::Foil,
+        Edition::Holographic,
+        Edition::Polychrome,
+        Edition::Negative,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Edition::Foil => "Foil",
+            Edition::Holographic => "Holographic",
+            Edition::Polychrome => "Polychrome",
+            Edition::Negative => "Negative",
+        }
+    }
+}
+
+/// Seals add an effect on top of a card's enhancement and edition.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Seal {
+    /// $3 when the card is scored.
+    Gold,
+    /// Retriggers the card.
+    Red,
+    /// Creates the Planet card of the final hand if held in hand at end of round.
+    Blue,
+    /// Creates a Tarot card when discarded.
+    Purple,
+}
+
+impl Seal {
+    pub const ALL: &'static [Seal] = &[Seal::Gold, Seal::Red, Seal::Blue, Seal::Purple];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Seal::Gold => "Gold Seal",
+            Seal::Red => "Red Seal",
+            Seal::Blue => "Blue Seal",
+            Seal::Purple => "Purple Seal",
+        }
+    }
+}
+
+/// Starting decks whose effects reach scoring.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DeckVariant {
+    /// Chips and Mult are balanced before multiplying; blind requirements are doubled.
+    Plasma,
+    /// 26 Spades and 26 Hearts.
+    Checkered,
+    /// No face cards.
+    Abandoned,
+    /// Every card has a random rank and suit.
+    Erratic,
+    /// +2 hand size, -1 joker slot.
+    Painted,
+    /// Starts with Tarot Merchant, Planet Merchant and Overstock.
+    Zodiac,
+    /// Double Tag after each Boss Blind, which does not affect scoring.
+    Anaglyph,
+}
+
+impl DeckVariant {
+    pub const ALL: &'static [DeckVariant] = &[
+        DeckVariant::Plasma,
+        DeckVariant::Checkered,
+        DeckVariant::Abandoned,
+        DeckVariant::Erratic,
+        DeckVariant::Painted,
+        DeckVariant::Zodiac,
+        DeckVariant::Anaglyph,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeckVariant::Plasma => "Plasma Deck",
+            DeckVariant::Checkered => "Checkered Deck",
+            DeckVariant::Abandoned => "Abandoned Deck",
+            DeckVariant::Erratic => "Erratic Deck",
+            DeckVariant::Painted => "Painted Deck",
+            DeckVariant::Zodiac => "Zodiac Deck",
+            DeckVariant::Anaglyph => "Anaglyph Deck",
+        }
+    }
+
+    /// Factor applied to every blind's chip requirement.
+    pub fn blind_multiplier(&self) -> i64 {
+        match self {
+            DeckVariant::Plasma => 2,
+            _ => 1,
+        }
+    }
+
+    /// Extra cards drawn to the hand.
+    pub fn hand_size_bonus(&self) -> usize {
+        match self {
+            DeckVariant::Painted => 2,
+            _ => 0,
+        }
+    }
+
+    /// Joker slots the deck takes away.
+    pub fn joker_slot_penalty(&self) -> usize {
+        match self {
+            DeckVariant::Painted => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Implements `Display`, `Debug` and `FromStr` through a type's `ALL` list and `name`.
+macro_rules! named {
+    ($($ty:ident),*) => {$(
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.name())
+            }
+        }
+
+        impl std::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $ty::ALL
+                    .iter()
+                    .find(|value| value.name() == s)
+                    .copied()
+                    .ok_or_else(|| format!("Invalid {}: `{s}`", stringify!($ty)))
+            }
+        }
+    )*};
+}
+
+pub(crate) use named;
+
+named!(Joker, Enhancement, Edition, Seal, DeckVariant);
+
+/// Implements string (de)serialization through `Display` and `FromStr`.
+macro_rules! string_serde {
+    ($($ty:ident),*) => {$(
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <String as serde::Deserialize>::deserialize(deserializer)?
+                    .parse()
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    )*};
+}
+
+pub(crate) use string_serde;
+
+string_serde!(Card, JokerCard, DeckVariant);
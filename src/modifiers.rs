@@ -1,18 +1,30 @@
-use ortalib::{
-    Card, Chips, Edition as EditionType, Enhancement as EnhancementType, Mult, Rank, Suit,
+use crate::model::{
+    Card, Edition as EditionType, Enhancement as EnhancementType, Rank, Seal as SealType, Suit,
 };
 use std::collections::HashMap;
 
+use crate::bignum::BigNum;
+use crate::ops::ScoreOp;
+use crate::rng::Rng;
+
 pub trait Enhancement {
-    fn apply(&self, chips: &mut Chips, mult: &mut Mult, card: &Card, is_held: bool);
+    fn apply(&self, card: &Card, is_held: bool) -> Vec<ScoreOp>;
     fn name(&self) -> &'static str;
+    /// Dollars earned when the card is still held in hand at the end of the round.
+    fn end_of_round_money(&self) -> i64 {
+        0
+    }
+    /// Effects that trigger by chance when the card is scored.
+    fn apply_chance(&self, _rng: &mut Rng) -> Vec<ScoreOp> {
+        Vec::new()
+    }
 }
 
 pub struct BonusEnhancement;
 
 impl Enhancement for BonusEnhancement {
-    fn apply(&self, chips: &mut Chips, _mult: &mut Mult, _card: &Card, _is_held: bool) {
-        *chips += 30.0;
+    fn apply(&self, _card: &Card, _is_held: bool) -> Vec<ScoreOp> {
+        vec![ScoreOp::AddChips(BigNum::from(30))]
     }
 
     fn name(&self) -> &'static str {
@@ -23,8 +35,8 @@ impl Enhancement for BonusEnhancement {
 pub struct MultEnhancement;
 
 impl Enhancement for MultEnhancement {
-    fn apply(&self, _chips: &mut Chips, mult: &mut Mult, _card: &Card, _is_held: bool) {
-        *mult += 4.0;
+    fn apply(&self, _card: &Card, _is_held: bool) -> Vec<ScoreOp> {
+        vec![ScoreOp::AddMult(BigNum::from(4))]
     }
 
     fn name(&self) -> &'static str {
@@ -35,7 +47,9 @@ impl Enhancement for MultEnhancement {
 pub struct WildEnhancement;
 
 impl Enhancement for WildEnhancement {
-    fn apply(&self, _chips: &mut Chips, _mult: &mut Mult, _card: &Card, _is_held: bool) {}
+    fn apply(&self, _card: &Card, _is_held: bool) -> Vec<ScoreOp> {
+        Vec::new()
+    }
 
     fn name(&self) -> &'static str {
         "Wild Card"
@@ -45,8 +59,8 @@ impl Enhancement for WildEnhancement {
 pub struct GlassEnhancement;
 
 impl Enhancement for GlassEnhancement {
-    fn apply(&self, _chips: &mut Chips, mult: &mut Mult, _card: &Card, _is_held: bool) {
-        *mult *= 2.0;
+    fn apply(&self, _card: &Card, _is_held: bool) -> Vec<ScoreOp> {
+        vec![ScoreOp::MulMult(BigNum::from(2))]
     }
 
     fn name(&self) -> &'static str {
@@ -57,9 +71,11 @@ impl Enhancement for GlassEnhancement {
 pub struct SteelEnhancement;
 
 impl Enhancement for SteelEnhancement {
-    fn apply(&self, _chips: &mut Chips, mult: &mut Mult, _card: &Card, is_held: bool) {
+    fn apply(&self, _card: &Card, is_held: bool) -> Vec<ScoreOp> {
         if is_held {
-            *mult *= 1.5;
+            vec![ScoreOp::MulMult(BigNum::new(15, 1))]
+        } else {
+            Vec::new()
         }
     }
 
@@ -68,6 +84,61 @@ impl Enhancement for SteelEnhancement {
     }
 }
 
+pub struct GoldEnhancement;
+
+impl Enhancement for GoldEnhancement {
+    fn apply(&self, _card: &Card, _is_held: bool) -> Vec<ScoreOp> {
+        Vec::new()
+    }
+
+    fn name(&self) -> &'static str {
+        "Gold Card"
+    }
+
+    fn end_of_round_money(&self) -> i64 {
+        3
+    }
+}
+
+pub struct LuckyEnhancement;
+
+impl Enhancement for LuckyEnhancement {
+    fn apply(&self, _card: &Card, _is_held: bool) -> Vec<ScoreOp> {
+        Vec::new()
+    }
+
+    fn name(&self) -> &'static str {
+        "Lucky Card"
+    }
+
+    fn apply_chance(&self, rng: &mut Rng) -> Vec<ScoreOp> {
+        let mut ops = Vec::new();
+        if rng.below(5) == 0 {
+            ops.push(ScoreOp::AddMult(BigNum::from(20)));
+        }
+        if rng.below(15) == 0 {
+            ops.push(ScoreOp::EarnMoney(20));
+        }
+        ops
+    }
+}
+
+pub struct StoneEnhancement;
+
+impl Enhancement for StoneEnhancement {
+    fn apply(&self, _card: &Card, is_held: bool) -> Vec<ScoreOp> {
+        if !is_held {
+            vec![ScoreOp::AddChips(BigNum::from(50))]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Stone Card"
+    }
+}
+
 /// Creates an enhancement handler based on the enhancement type.
 pub fn create_enhancement_handler(enhancement_type: &EnhancementType) -> Box<dyn Enhancement> {
     match enhancement_type {
@@ -76,19 +147,22 @@ pub fn create_enhancement_handler(enhancement_type: &EnhancementType) -> Box<dyn
         EnhancementType::Wild => Box::new(WildEnhancement),
         EnhancementType::Glass => Box::new(GlassEnhancement),
         EnhancementType::Steel => Box::new(SteelEnhancement),
+        EnhancementType::Gold => Box::new(GoldEnhancement),
+        EnhancementType::Lucky => Box::new(LuckyEnhancement),
+        EnhancementType::Stone => Box::new(StoneEnhancement),
     }
 }
 
 pub trait Edition {
-    fn apply(&self, chips: &mut Chips, mult: &mut Mult, card: &Card);
+    fn apply(&self, card: &Card) -> Vec<ScoreOp>;
     fn name(&self) -> &'static str;
 }
 
 pub struct FoilEdition;
 
 impl Edition for FoilEdition {
-    fn apply(&self, chips: &mut Chips, _mult: &mut Mult, _card: &Card) {
-        *chips += 50.0;
+    fn apply(&self, _card: &Card) -> Vec<ScoreOp> {
+        vec![ScoreOp::AddChips(BigNum::from(50))]
     }
 
     fn name(&self) -> &'static str {
@@ -99,8 +173,8 @@ impl Edition for FoilEdition {
 pub struct HolographicEdition;
 
 impl Edition for HolographicEdition {
-    fn apply(&self, _chips: &mut Chips, mult: &mut Mult, _card: &Card) {
-        *mult += 10.0;
+    fn apply(&self, _card: &Card) -> Vec<ScoreOp> {
+        vec![ScoreOp::AddMult(BigNum::from(10))]
     }
 
     fn name(&self) -> &'static str {
@@ -111,8 +185,8 @@ impl Edition for HolographicEdition {
 pub struct PolychromeEdition;
 
 impl Edition for PolychromeEdition {
-    fn apply(&self, _chips: &mut Chips, mult: &mut Mult, _card: &Card) {
-        *mult *= 1.5;
+    fn apply(&self, _card: &Card) -> Vec<ScoreOp> {
+        vec![ScoreOp::MulMult(BigNum::new(15, 1))]
     }
 
     fn name(&self) -> &'static str {
@@ -120,28 +194,98 @@ impl Edition for PolychromeEdition {
     }
 }
 
+pub struct NegativeEdition;
+
+impl Edition for NegativeEdition {
+    fn apply(&self, _card: &Card) -> Vec<ScoreOp> {
+        Vec::new()
+    }
+
+    fn name(&self) -> &'static str {
+        "Negative"
+    }
+}
+
 /// Creates an edition handler based on the edition type.
 pub fn create_edition_handler(edition_type: &EditionType) -> Box<dyn Edition> {
     match edition_type {
         EditionType::Foil => Box::new(FoilEdition),
         EditionType::Holographic => Box::new(HolographicEdition),
         EditionType::Polychrome => Box::new(PolychromeEdition),
+        EditionType::Negative => Box::new(NegativeEdition),
+    }
+}
+
+pub trait Seal {
+    fn name(&self) -> &'static str;
+    /// Dollars earned each time the card is scored.
+    fn money_on_scored(&self) -> i64 {
+        0
+    }
+    /// Extra times the card is scored.
+    fn retriggers(&self) -> usize {
+        0
     }
 }
 
-/// Applies enhancements and editions to a set of cards, modifying chips and mult.
-pub fn apply_enhancements(cards: &Vec<Card>, chips: &mut Chips, mult: &mut Mult, is_held: bool) {
+pub struct GoldSeal;
+
+impl Seal for GoldSeal {
+    fn name(&self) -> &'static str {
+        "Gold Seal"
+    }
+
+    fn money_on_scored(&self) -> i64 {
+        3
+    }
+}
+
+pub struct RedSeal;
+
+impl Seal for RedSeal {
+    fn name(&self) -> &'static str {
+        "Red Seal"
+    }
+
+    fn retriggers(&self) -> usize {
+        1
+    }
+}
+
+/// Blue and Purple seals create consumables, which do not affect the score.
+pub struct ConsumableSeal(&'static str);
+
+impl Seal for ConsumableSeal {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// Creates a seal handler based on the seal type.
+pub fn create_seal_handler(seal_type: &SealType) -> Box<dyn Seal> {
+    match seal_type {
+        SealType::Gold => Box::new(GoldSeal),
+        SealType::Red => Box::new(RedSeal),
+        SealType::Blue => Box::new(ConsumableSeal("Blue Seal")),
+        SealType::Purple => Box::new(ConsumableSeal("Purple Seal")),
+    }
+}
+
+/// Collects the enhancement and edition operations of a set of cards.
+pub fn apply_enhancements(cards: &Vec<Card>, is_held: bool) -> Vec<ScoreOp> {
+    let mut ops = Vec::new();
     for card in cards {
         if let Some(enhancement_type) = &card.enhancement {
             let enhancement = create_enhancement_handler(enhancement_type);
-            enhancement.apply(chips, mult, card, is_held);
+            ops.extend(enhancement.apply(card, is_held));
         }
 
         if let Some(edition_type) = &card.edition {
             let edition = create_edition_handler(edition_type);
-            edition.apply(chips, mult, card);
+            ops.extend(edition.apply(card));
         }
     }
+    ops
 }
 
 /// Handles wild cards by adjusting the card set, potentially forming a straight.
@@ -167,7 +311,8 @@ pub fn handle_wild(cards: &[Card]) -> Vec<Card> {
 
 /// Generates all possible straight sequences of ranks.
 fn get_possible_straight_sequences() -> Vec<Vec<Rank>> {
-    let all_ranks = [Rank::Two,
+    let all_ranks = [
+        Rank::Two,
         Rank::Three,
         Rank::Four,
         Rank::Five,
@@ -179,7 +324,8 @@ fn get_possible_straight_sequences() -> Vec<Vec<Rank>> {
         Rank::Jack,
         Rank::Queen,
         Rank::King,
-        Rank::Ace];
+        Rank::Ace,
+    ];
     let mut sequences = Vec::new();
 
     for i in 0..=all_ranks.len().saturating_sub(5) {
@@ -226,17 +372,10 @@ fn select_best_suit(cards: &[Card]) -> Suit {
         *suit_counts.entry(card.suit).or_insert(0) += 1;
     }
 
-    if !suit_counts.is_empty() {
-        suit_counts
-            .into_iter()
-            .max_by_key(|(_, count)| *count)
-            .map(|(suit, _)| suit)
-            .unwrap()
-    } else {
-        let all_suits = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
-
-        all_suits[0]
-    }
+    suit_counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map_or(Suit::Clubs, |(suit, _)| suit)
 }
 
 /// Converts a set of all wild cards into a straight sequence if possible.
@@ -245,22 +384,23 @@ pub fn handle_all_wild_cards(cards: &[Card]) -> Option<Vec<Card>> {
         .iter()
         .all(|card| matches!(card.enhancement, Some(EnhancementType::Wild)));
 
-    if all_wild {
-        if let Some(target_seq) = select_best_straight_sequence() {
-            let selected_suit = select_best_suit(cards);
-            let mut result = Vec::new();
-
-            for r in target_seq.iter() {
-                let mut card = cards[0];
-                card.rank = *r;
-                card.suit = selected_suit;
-                card.enhancement = None;
-                result.push(card);
-            }
+    if all_wild
+        && let Some(&first) = cards.first()
+        && let Some(target_seq) = select_best_straight_sequence()
+    {
+        let selected_suit = select_best_suit(cards);
+        let mut result = Vec::new();
+
+        for r in target_seq.iter() {
+            let mut card = first;
+            card.rank = *r;
+            card.suit = selected_suit;
+            card.enhancement = None;
+            result.push(card);
+        }
 
-            if result.len() == 5 {
-                return Some(result);
-            }
+        if result.len() == 5 {
+            return Some(result);
         }
     }
 
@@ -311,7 +451,7 @@ pub fn try_form_wild_straight(cards: &[Card]) -> Option<Vec<Card>> {
         }
 
         if result.len() == 5 {
-            result.sort_by(|a, b| b.rank.cmp(&a.rank));
+            result.sort_by_key(|card| std::cmp::Reverse(card.rank));
             return Some(result);
         }
     }
@@ -319,11 +459,12 @@ pub fn try_form_wild_straight(cards: &[Card]) -> Option<Vec<Card>> {
     None
 }
 
-/// Applies an edition effect directly to chips and mult based on the edition type.
-pub fn apply_edition_effect(edition_type: &EditionType, chips: &mut Chips, mult: &mut Mult) {
+/// Returns the operations of an edition on a joker, based on the edition type.
+pub fn apply_edition_effect(edition_type: &EditionType) -> Vec<ScoreOp> {
     match edition_type {
-        EditionType::Foil => *chips += 50.0,
-        EditionType::Holographic => *mult += 10.0,
-        EditionType::Polychrome => *mult *= 1.5,
+        EditionType::Foil => vec![ScoreOp::AddChips(BigNum::from(50))],
+        EditionType::Holographic => vec![ScoreOp::AddMult(BigNum::from(10))],
+        EditionType::Polychrome => vec![ScoreOp::MulMult(BigNum::new(15, 1))],
+        EditionType::Negative => Vec::new(),
     }
 }
@@ -0,0 +1,83 @@
+use std::fmt;
+
+use crate::bignum::{BigNum, Chips, Mult};
+use crate::model::{Card, JokerCard};
+
+/// A change an effect makes to a hand, applied by the scoring engine.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScoreOp {
+    AddChips(BigNum),
+    AddMult(BigNum),
+    MulMult(BigNum),
+    /// Scores the current card again; ignored outside per-card phases.
+    Retrigger,
+    EarnMoney(i64),
+}
+
+impl ScoreOp {
+    /// Applies the operation to Chips and Mult, returning the dollars it earns.
+    pub fn apply(&self, chips: &mut Chips, mult: &mut Mult) -> i64 {
+        match self {
+            ScoreOp::AddChips(value) => *chips += value,
+            ScoreOp::AddMult(value) => *mult += value,
+            ScoreOp::MulMult(value) => *mult *= value,
+            ScoreOp::Retrigger => {}
+            ScoreOp::EarnMoney(dollars) => return *dollars,
+        }
+        0
+    }
+}
+
+impl fmt::Display for ScoreOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScoreOp::AddChips(value) => write!(f, "+{} Chips", value),
+            ScoreOp::AddMult(value) => write!(f, "+{} Mult", value),
+            ScoreOp::MulMult(value) => write!(f, "x{} Mult", value),
+            ScoreOp::Retrigger => write!(f, "Retrigger"),
+            ScoreOp::EarnMoney(dollars) => write!(f, "+${}", dollars),
+        }
+    }
+}
+
+/// What produced a score operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpSource {
+    /// The base Chips and Mult of the poker hand.
+    Hand,
+    Card(Card),
+    Joker(JokerCard),
+}
+
+impl fmt::Display for OpSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpSource::Hand => write!(f, "Poker hand"),
+            OpSource::Card(card) => write!(f, "{}", card),
+            OpSource::Joker(joker) => write!(f, "{}", joker),
+        }
+    }
+}
+
+/// A score operation with what produced it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourcedOp {
+    pub source: OpSource,
+    pub op: ScoreOp,
+}
+
+impl SourcedOp {
+    /// Tags every operation in a list with the same source.
+    pub fn all(source: OpSource, ops: Vec<ScoreOp>) -> impl Iterator<Item = SourcedOp> {
+        ops.into_iter().map(move |op| SourcedOp { source, op })
+    }
+}
+
+/// An operation as the engine applied it, with Chips and Mult right after.
+#[derive(Clone, Debug)]
+pub struct TracedOp {
+    pub source: OpSource,
+    pub op: ScoreOp,
+    pub chips: Chips,
+    pub mult: Mult,
+}
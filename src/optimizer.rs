@@ -0,0 +1,238 @@
+use std::fmt;
+
+use crate::model::{JokerCard, Round};
+
+use crate::bignum::BigNum;
+use crate::score::{ScoreError, ScoreManager};
+
+/// Lineups with more distinct orders than this are searched locally instead of exhaustively.
+const MAX_EXHAUSTIVE_ORDERS: u128 = 40_320;
+
+/// Outcome of searching joker slot orders for the highest scoring lineup.
+pub struct OrderReport {
+    pub current_order: Vec<JokerCard>,
+    pub current_score: BigNum,
+    pub best_order: Vec<JokerCard>,
+    pub best_score: BigNum,
+    pub orders_tried: usize,
+    pub exhaustive: bool,
+}
+
+impl fmt::Display for OrderReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Current order: {} (Score: {})",
+            format_order(&self.current_order),
+            self.current_score
+        )?;
+        writeln!(
+            f,
+            "Best order: {} (Score: {})",
+            format_order(&self.best_order),
+            self.best_score
+        )?;
+        write!(
+            f,
+            "Orders tried: {} ({})",
+            self.orders_tried,
+            if self.exhaustive {
+                "exhaustive"
+            } else {
+                "local search"
+            }
+        )
+    }
+}
+
+/// Formats a joker lineup as a comma separated list.
+fn format_order(jokers: &[JokerCard]) -> String {
+    if jokers.is_empty() {
+        return "(no jokers)".to_string();
+    }
+
+    jokers
+        .iter()
+        .map(|joker| joker.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Scores a round with its jokers rearranged into the given order.
+pub fn score_with_order(round: &Round, jokers: &[JokerCard]) -> Result<BigNum, ScoreError> {
+    let reordered = Round {
+        jokers: jokers.to_vec(),
+        ..round.clone()
+    };
+    ScoreManager::from_round(&reordered).calculate_score()
+}
+
+/// Finds the joker order that maximizes the score of a round.
+pub fn optimize_joker_order(round: &Round) -> Result<OrderReport, ScoreError> {
+    let current_order = round.jokers.clone();
+    let current_score = score_with_order(round, &current_order)?;
+
+    let classes = joker_classes(&current_order);
+    let exhaustive = count_distinct_orders(&classes) <= MAX_EXHAUSTIVE_ORDERS;
+
+    let (best_order, best_score, orders_tried) = if exhaustive {
+        search_all_orders(round, &classes, current_score.clone())?
+    } else {
+        search_locally(round, current_score.clone())?
+    };
+
+    Ok(OrderReport {
+        current_order,
+        current_score,
+        best_order,
+        best_score,
+        orders_tried,
+        exhaustive,
+    })
+}
+
+/// Assigns each joker the index of the first joker identical to it in kind, edition
+/// and stickers, so swapping interchangeable jokers is never tried twice.
+fn joker_classes(jokers: &[JokerCard]) -> Vec<usize> {
+    jokers
+        .iter()
+        .map(|joker| {
+            jokers
+                .iter()
+                .position(|other| {
+                    other.joker == joker.joker
+                        && other.edition == joker.edition
+                        && other.stickers == joker.stickers
+                })
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+/// Counts the distinct orders of a lineup, treating identical jokers as one.
+fn count_distinct_orders(classes: &[usize]) -> u128 {
+    let mut total: u128 = 1;
+    for n in 2..=classes.len() as u128 {
+        total = total.saturating_mul(n);
+    }
+
+    for class in 0..classes.len() {
+        let copies = classes.iter().filter(|&&c| c == class).count() as u128;
+        for n in 2..=copies {
+            total /= n;
+        }
+    }
+
+    total
+}
+
+/// Rearranges the next lexicographic permutation in place, returning false once exhausted.
+fn next_permutation(order: &mut [usize]) -> bool {
+    if order.len() < 2 {
+        return false;
+    }
+
+    let mut i = order.len() - 1;
+    while i > 0 && order[i - 1] >= order[i] {
+        i -= 1;
+    }
+    if i == 0 {
+        return false;
+    }
+
+    let mut j = order.len() - 1;
+    while order[j] <= order[i - 1] {
+        j -= 1;
+    }
+    order.swap(i - 1, j);
+    order[i..].reverse();
+    true
+}
+
+/// Builds a lineup from a sequence of joker classes.
+fn order_from_classes(
+    jokers: &[JokerCard],
+    classes: &[usize],
+    sequence: &[usize],
+) -> Vec<JokerCard> {
+    let mut used = vec![false; jokers.len()];
+    sequence
+        .iter()
+        .map(|&class| {
+            let index = (0..jokers.len())
+                .find(|&i| !used[i] && classes[i] == class)
+                .unwrap_or(class);
+            used[index] = true;
+            jokers[index]
+        })
+        .collect()
+}
+
+/// Tries every distinct order of the lineup.
+fn search_all_orders(
+    round: &Round,
+    classes: &[usize],
+    current_score: BigNum,
+) -> Result<(Vec<JokerCard>, BigNum, usize), ScoreError> {
+    let mut best_order = round.jokers.clone();
+    let mut best_score = current_score;
+    let mut orders_tried = 0;
+
+    let mut sequence = classes.to_vec();
+    sequence.sort_unstable();
+
+    loop {
+        let order = order_from_classes(&round.jokers, classes, &sequence);
+        let score = score_with_order(round, &order)?;
+        orders_tried += 1;
+
+        if score > best_score {
+            best_score = score;
+            best_order = order;
+        }
+
+        if !next_permutation(&mut sequence) {
+            break;
+        }
+    }
+
+    Ok((best_order, best_score, orders_tried))
+}
+
+/// Improves the current order by moving single jokers until no move raises the score.
+fn search_locally(
+    round: &Round,
+    current_score: BigNum,
+) -> Result<(Vec<JokerCard>, BigNum, usize), ScoreError> {
+    let mut best_order = round.jokers.clone();
+    let mut best_score = current_score;
+    let mut orders_tried = 1;
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+
+        for from in 0..best_order.len() {
+            for to in 0..best_order.len() {
+                if from == to {
+                    continue;
+                }
+
+                let mut candidate = best_order.clone();
+                let joker = candidate.remove(from);
+                candidate.insert(to, joker);
+
+                let score = score_with_order(round, &candidate)?;
+                orders_tried += 1;
+
+                if score > best_score {
+                    best_score = score;
+                    best_order = candidate;
+                    improved = true;
+                }
+            }
+        }
+    }
+
+    Ok((best_order, best_score, orders_tried))
+}
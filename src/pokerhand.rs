@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use ortalib::{Card, Chips, JokerCard, Mult, Rank, Suit};
+use ortalib::{Chips, Mult};
+
+use crate::model::{Card, Joker, JokerCard, Rank, Suit};
 
 pub trait HandEvaluator {
     fn evaluate(&self, cards: &[Card], jokers: &[JokerCard]) -> bool;
@@ -39,7 +41,7 @@ impl HandEvaluator for HighCard {
             return Vec::new();
         }
         let mut sorted_cards: Vec<&Card> = cards.iter().collect();
-        sorted_cards.sort_by(|a, b| b.rank.cmp(&a.rank));
+        sorted_cards.sort_by_key(|card| std::cmp::Reverse(card.rank));
         vec![sorted_cards[0]]
     }
 
@@ -206,10 +208,7 @@ impl HandEvaluator for Flush {
 
         let mut suit_groups: HashMap<Suit, Vec<&Card>> = HashMap::new();
         for card in cards {
-            suit_groups
-                .entry(card.suit)
-                .or_default()
-                .push(card);
+            suit_groups.entry(card.suit).or_default().push(card);
         }
 
         let flush_suit = suit_groups
@@ -219,7 +218,7 @@ impl HandEvaluator for Flush {
 
         if let Some((_, flush_cards)) = flush_suit {
             let mut best_flush_cards = flush_cards.clone();
-            best_flush_cards.sort_by(|a, b| b.rank.cmp(&a.rank));
+            best_flush_cards.sort_by_key(|card| std::cmp::Reverse(card.rank));
             best_flush_cards.truncate(min_cards_needed);
             return best_flush_cards;
         }
@@ -288,10 +287,7 @@ impl HandEvaluator for FullHouse {
 
         let mut rank_groups: HashMap<Rank, Vec<&Card>> = HashMap::new();
         for card in cards {
-            rank_groups
-                .entry(card.rank)
-                .or_default()
-                .push(card);
+            rank_groups.entry(card.rank).or_default().push(card);
         }
 
         let mut result = Vec::new();
@@ -361,10 +357,7 @@ impl HandEvaluator for FourOfAKind {
 
         let mut rank_groups: HashMap<Rank, Vec<&Card>> = HashMap::new();
         for card in cards {
-            rank_groups
-                .entry(card.rank)
-                .or_default()
-                .push(card);
+            rank_groups.entry(card.rank).or_default().push(card);
         }
 
         let best_quad_rank = quad_ranks[0];
@@ -424,10 +417,7 @@ impl HandEvaluator for Straight {
         let mut order_to_cards: HashMap<u8, Vec<&Card>> = HashMap::new();
         for card in cards {
             let order = rank_to_order(card.rank);
-            order_to_cards
-                .entry(order)
-                .or_default()
-                .push(card);
+            order_to_cards.entry(order).or_default().push(card);
             if card.rank == Rank::Ace {
                 order_to_cards.entry(1).or_default().push(card);
             }
@@ -440,10 +430,10 @@ impl HandEvaluator for Straight {
             if window[window.len() - 1] - window[0] == (min_cards_needed - 1) as u8 {
                 let mut result = Vec::new();
                 for &order in window.iter().rev().take(min_cards_needed) {
-                    if let Some(card_list) = order_to_cards.get(&order) {
-                        if !card_list.is_empty() {
-                            result.push(card_list[0]);
-                        }
+                    if let Some(card_list) = order_to_cards.get(&order)
+                        && !card_list.is_empty()
+                    {
+                        result.push(card_list[0]);
                     }
                 }
                 if result.len() == min_cards_needed {
@@ -460,10 +450,10 @@ impl HandEvaluator for Straight {
                     if valid {
                         let mut result = Vec::new();
                         for &order in window.iter().rev().take(min_cards_needed) {
-                            if let Some(card_list) = order_to_cards.get(&order) {
-                                if !card_list.is_empty() {
-                                    result.push(card_list[0]);
-                                }
+                            if let Some(card_list) = order_to_cards.get(&order)
+                                && !card_list.is_empty()
+                            {
+                                result.push(card_list[0]);
                             }
                         }
                         if result.len() == min_cards_needed {
@@ -535,10 +525,7 @@ impl HandEvaluator for StraightFlush {
             let mut order_to_cards: HashMap<u8, Vec<&Card>> = HashMap::new();
             for &card in suit_cards.iter() {
                 let order = rank_to_order(card.rank);
-                order_to_cards
-                    .entry(order)
-                    .or_default()
-                    .push(card);
+                order_to_cards.entry(order).or_default().push(card);
                 if card.rank == Rank::Ace {
                     order_to_cards.entry(1).or_default().push(card);
                 }
@@ -551,10 +538,10 @@ impl HandEvaluator for StraightFlush {
                 if window[window.len() - 1] - window[0] == (min_cards_needed - 1) as u8 {
                     let mut result = Vec::new();
                     for &order in window.iter().rev().take(min_cards_needed) {
-                        if let Some(card_list) = order_to_cards.get(&order) {
-                            if !card_list.is_empty() {
-                                result.push(card_list[0]);
-                            }
+                        if let Some(card_list) = order_to_cards.get(&order)
+                            && !card_list.is_empty()
+                        {
+                            result.push(card_list[0]);
                         }
                     }
                     if result.len() == min_cards_needed {
@@ -571,10 +558,10 @@ impl HandEvaluator for StraightFlush {
                         if valid {
                             let mut result = Vec::new();
                             for &order in window.iter().rev().take(min_cards_needed) {
-                                if let Some(card_list) = order_to_cards.get(&order) {
-                                    if !card_list.is_empty() {
-                                        result.push(card_list[0]);
-                                    }
+                                if let Some(card_list) = order_to_cards.get(&order)
+                                    && !card_list.is_empty()
+                                {
+                                    result.push(card_list[0]);
                                 }
                             }
                             if result.len() == min_cards_needed {
@@ -632,10 +619,7 @@ impl HandEvaluator for FiveOfAKind {
 
         let mut rank_groups: HashMap<Rank, Vec<&Card>> = HashMap::new();
         for card in cards {
-            rank_groups
-                .entry(card.rank)
-                .or_default()
-                .push(card);
+            rank_groups.entry(card.rank).or_default().push(card);
         }
 
         let best_quint_rank = quint_ranks[0];
@@ -666,10 +650,7 @@ impl HandEvaluator for FlushHouse {
 
         let mut suit_groups: HashMap<Suit, Vec<&Card>> = HashMap::new();
         for card in cards {
-            suit_groups
-                .entry(card.suit)
-                .or_default()
-                .push(card);
+            suit_groups.entry(card.suit).or_default().push(card);
         }
 
         for (_, suit_cards) in suit_groups.iter().filter(|(_, cards)| cards.len() >= 5) {
@@ -699,10 +680,7 @@ impl HandEvaluator for FlushHouse {
 
         let mut suit_groups: HashMap<Suit, Vec<&Card>> = HashMap::new();
         for card in cards {
-            suit_groups
-                .entry(card.suit)
-                .or_default()
-                .push(card);
+            suit_groups.entry(card.suit).or_default().push(card);
         }
 
         for (_, suit_cards) in suit_groups.iter().filter(|(_, cards)| cards.len() >= 5) {
@@ -731,10 +709,7 @@ impl HandEvaluator for FlushHouse {
 
             let mut rank_groups: HashMap<Rank, Vec<&Card>> = HashMap::new();
             for card in suit_cards {
-                rank_groups
-                    .entry(card.rank)
-                    .or_default()
-                    .push(card);
+                rank_groups.entry(card.rank).or_default().push(card);
             }
 
             let mut result = Vec::new();
@@ -781,10 +756,7 @@ impl HandEvaluator for FlushFive {
 
         let mut suit_groups: HashMap<Suit, Vec<&Card>> = HashMap::new();
         for card in cards {
-            suit_groups
-                .entry(card.suit)
-                .or_default()
-                .push(card);
+            suit_groups.entry(card.suit).or_default().push(card);
         }
 
         for (_, suit_cards) in suit_groups.iter() {
@@ -808,10 +780,7 @@ impl HandEvaluator for FlushFive {
 
         let mut suit_groups: HashMap<Suit, Vec<&Card>> = HashMap::new();
         for card in cards {
-            suit_groups
-                .entry(card.suit)
-                .or_default()
-                .push(card);
+            suit_groups.entry(card.suit).or_default().push(card);
         }
 
         for (_, suit_cards) in suit_groups.iter() {
@@ -831,10 +800,7 @@ impl HandEvaluator for FlushFive {
 
                 let mut rank_groups: HashMap<Rank, Vec<&Card>> = HashMap::new();
                 for &card in suit_cards {
-                    rank_groups
-                        .entry(card.rank)
-                        .or_default()
-                        .push(card);
+                    rank_groups.entry(card.rank).or_default().push(card);
                 }
 
                 let best_quint_rank = quint_ranks[0];
@@ -922,15 +888,14 @@ pub fn create_poker_hand() -> PokerHand {
 fn has_four_fingers_joker(jokers: &[JokerCard]) -> bool {
     jokers
         .iter()
-        .any(|joker| matches!(joker.joker, ortalib::Joker::FourFingers))
+        .any(|joker| matches!(joker.joker, Joker::FourFingers))
 }
 
 /// Checks if the Shortcut joker is present.
 fn has_shortcut_joker(jokers: &[JokerCard]) -> bool {
-    let has_shortcut = jokers
+    jokers
         .iter()
-        .any(|joker| matches!(joker.joker, ortalib::Joker::Shortcut));
-    has_shortcut
+        .any(|joker| matches!(joker.joker, Joker::Shortcut))
 }
 
 /// Determines the minimum cards needed based on jokers.
@@ -942,10 +907,7 @@ fn get_min_cards_needed(jokers: &[JokerCard]) -> usize {
 fn group_by_suit(cards: &[Card]) -> HashMap<Suit, Vec<&Card>> {
     let mut suit_groups: HashMap<Suit, Vec<&Card>> = HashMap::new();
     for card in cards {
-        suit_groups
-            .entry(card.suit)
-            .or_default()
-            .push(card);
+        suit_groups.entry(card.suit).or_default().push(card);
     }
     suit_groups
 }
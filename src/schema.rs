@@ -0,0 +1,99 @@
+//! JSON Schema for the round input format.
+//!
+//! Cards, jokers and deck variants are written as strings, so their schemas are built
+//! by hand from the same name lists the parsers use.
+
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema, schema_for};
+
+use crate::deck::{ALL_RANKS, ALL_SUITS};
+use crate::model::{Card, DeckVariant, Edition, Enhancement, Joker, JokerCard, Round, Seal};
+
+/// The schema of a round file, as accepted by `parse_round`.
+pub fn round_schema() -> Schema {
+    schema_for!(Round)
+}
+
+/// A regex alternation of names, e.g. `(Foil|Holographic)`.
+fn alternation(names: impl IntoIterator<Item = String>) -> String {
+    format!("({})", names.into_iter().collect::<Vec<_>>().join("|"))
+}
+
+fn edition_names() -> String {
+    alternation(
+        Edition::ALL
+            .iter()
+            .map(|edition| edition.name().to_string()),
+    )
+}
+
+impl JsonSchema for Card {
+    fn schema_name() -> Cow<'static, str> {
+        "Card".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        let modifiers = alternation(
+            Enhancement::ALL
+                .iter()
+                .map(|enhancement| enhancement.name().to_string())
+                .chain(
+                    Edition::ALL
+                        .iter()
+                        .map(|edition| edition.name().to_string()),
+                )
+                .chain(Seal::ALL.iter().map(|seal| seal.name().to_string())),
+        );
+        let pattern = format!(
+            "^{}{}( {})*$",
+            alternation(ALL_RANKS.iter().map(|rank| rank.to_string())),
+            alternation(ALL_SUITS.iter().map(|suit| suit.to_string())),
+            modifiers
+        );
+        json_schema!({
+            "type": "string",
+            "description": "A rank and suit, optionally followed by an enhancement, an edition and a seal.",
+            "pattern": pattern,
+            "examples": ["A♠", "K♥ Gold Foil Gold Seal"],
+        })
+    }
+}
+
+impl JsonSchema for JokerCard {
+    fn schema_name() -> Cow<'static, str> {
+        "JokerCard".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        let pattern = format!(
+            "^{}( {})?( (Eternal|Perishable( [0-9]+)?|Rental))*$",
+            alternation(Joker::ALL.iter().map(|joker| joker.name().to_string())),
+            edition_names()
+        );
+        json_schema!({
+            "type": "string",
+            "description": "A joker name, optionally followed by an edition and stickers.",
+            "pattern": pattern,
+            "examples": ["Greedy Joker Foil Eternal", "Mime Perishable 2 Rental"],
+        })
+    }
+}
+
+impl JsonSchema for DeckVariant {
+    fn schema_name() -> Cow<'static, str> {
+        "DeckVariant".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        let names: Vec<&str> = DeckVariant::ALL
+            .iter()
+            .map(|variant| variant.name())
+            .collect();
+        json_schema!({
+            "type": "string",
+            "description": "A starting deck whose effects reach scoring.",
+            "enum": names,
+        })
+    }
+}
@@ -1,7 +1,41 @@
-use crate::joker::{JokerActivation, JokerContext, JokerFactory, ScoringScope, get_joker_id};
+use std::{error::Error, fmt};
+
+use crate::bignum::{BigNum, Chips, Mult};
+use crate::economy::{self, Earnings};
+use crate::joker::{JokerContext, JokerEvent, JokerHooks, Phase, ScoringScope};
+use crate::model::{Card, Enhancement, Joker, JokerCard, RENTAL_COST, Round, RunState};
 use crate::modifiers::{self, handle_wild};
+use crate::ops::{OpSource, ScoreOp, SourcedOp, TracedOp};
 use crate::pokerhand::create_poker_hand;
-use ortalib::{Card, Chips, JokerCard, Mult, Round};
+use crate::rng::Rng;
+
+/// Why a round could not be scored.
+#[derive(Debug, PartialEq)]
+pub enum ScoreError {
+    NoCardsPlayed,
+    /// A joker asked to score a custom set of cards, which the engine cannot do yet.
+    CustomScoringScope,
+}
+
+impl ScoreError {
+    /// Whether the error comes from the engine rather than from the round.
+    pub fn is_engine_error(&self) -> bool {
+        matches!(self, ScoreError::CustomScoringScope)
+    }
+}
+
+impl fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScoreError::NoCardsPlayed => write!(f, "no cards played"),
+            ScoreError::CustomScoringScope => {
+                write!(f, "custom scoring scopes are not supported yet")
+            }
+        }
+    }
+}
+
+impl Error for ScoreError {}
 
 pub struct ScoreManager {
     cards_played: Vec<Card>,
@@ -12,7 +46,19 @@ pub struct ScoreManager {
     best_hand_cards: Vec<Card>,
     base_chips: Chips,
     base_mult: Mult,
+    /// Every joker in slot order, including debuffed ones.
+    lineup: Vec<JokerCard>,
+    /// Jokers that take part in scoring.
     jokers: Vec<JokerCard>,
+    /// Jokers that hold a slot but are skipped, such as Perishable jokers that ran out.
+    debuffed_jokers: Vec<JokerCard>,
+    run: RunState,
+    rng: Rng,
+    earnings: Earnings,
+    /// Chips and Mult before the Plasma Deck balanced them.
+    plasma_balance: Option<(Chips, Mult)>,
+    /// Every operation applied while scoring, in order.
+    trace: Vec<TracedOp>,
 }
 
 impl ScoreManager {
@@ -21,18 +67,45 @@ impl ScoreManager {
         ScoreManager {
             cards_played: round.cards_played.clone(),
             cards_in_hand: round.cards_held_in_hand.clone(),
-            chips: 0.0,
-            mult: 0.0,
+            chips: BigNum::zero(),
+            mult: BigNum::zero(),
             best_hand_name: None,
             best_hand_cards: Vec::new(),
-            base_chips: 0.0,
-            base_mult: 0.0,
-            jokers: round.jokers.clone(),
+            base_chips: BigNum::zero(),
+            base_mult: BigNum::zero(),
+            lineup: round.jokers.clone(),
+            jokers: round
+                .jokers
+                .iter()
+                .filter(|joker| !joker.is_debuffed())
+                .copied()
+                .collect(),
+            debuffed_jokers: round
+                .jokers
+                .iter()
+                .filter(|joker| joker.is_debuffed())
+                .copied()
+                .collect(),
+            run: round.run.clone(),
+            rng: Rng::new(round.run.seed),
+            earnings: Earnings {
+                starting_money: round.run.money,
+                ..Earnings::default()
+            },
+            plasma_balance: None,
+            trace: Vec::new(),
         }
     }
 
     /// Calculates the total score by evaluating the best poker hand and applying effects.
-    pub fn calculate_score(&mut self) -> f64 {
+    ///
+    /// Chips and mult are exact decimals at every step; the only rounding is the final
+    /// floor of `chips * mult`, matching the game.
+    pub fn calculate_score(&mut self) -> Result<BigNum, ScoreError> {
+        if self.cards_played.is_empty() {
+            return Err(ScoreError::NoCardsPlayed);
+        }
+
         let poker_hand = create_poker_hand();
         let mut cards_to_evaluate = handle_wild(&self.cards_played);
 
@@ -41,26 +114,22 @@ impl ScoreManager {
             cards_in_hand: &self.cards_in_hand,
             best_hand_name: self.best_hand_name.as_deref(),
             all_jokers: &self.jokers,
+            debuffed_jokers: &self.debuffed_jokers,
+            run: &self.run,
         };
 
-        let mut joker_effects = std::collections::HashMap::new();
-        for joker in &self.jokers {
-            let joker_id = get_joker_id(&joker.joker);
-            let joker_effect = JokerFactory::create_joker(&joker.joker);
-            joker_effects.insert(joker_id, joker_effect);
-        }
+        let hooks = JokerHooks::new(&self.jokers);
 
         if !self
             .cards_played
             .iter()
-            .any(|card| matches!(card.enhancement, Some(ortalib::Enhancement::Wild)))
+            .any(|card| matches!(card.enhancement, Some(Enhancement::Wild)))
         {
             cards_to_evaluate = context.with_modified_suits();
         }
 
         let mut scoring_scope = ScoringScope::BestHand;
-        for joker in &self.jokers {
-            let joker_effect = joker_effects.get(&get_joker_id(&joker.joker)).unwrap();
+        for joker_effect in hooks.effects() {
             let scope = joker_effect.scoring_scope(&context);
             if matches!(scope, ScoringScope::AllPlayed) {
                 scoring_scope = scope;
@@ -68,21 +137,32 @@ impl ScoreManager {
             }
         }
 
+        // Stone cards have no rank or suit, so they never form part of a poker hand.
+        cards_to_evaluate.retain(|card| !card.is_stone());
+
         if let Some((evaluator, hand_cards)) =
             poker_hand.find_best_hand(&cards_to_evaluate, &self.jokers)
         {
             self.best_hand_name = Some(evaluator.name().to_string());
             self.best_hand_cards = hand_cards.into_iter().cloned().collect();
+            self.best_hand_cards.extend(
+                self.cards_played
+                    .iter()
+                    .filter(|card| card.is_stone())
+                    .copied(),
+            );
+            self.run.record_hand_played(evaluator.name());
 
             let updated_context = JokerContext {
                 cards_played: &self.cards_played,
                 cards_in_hand: &self.cards_in_hand,
                 best_hand_name: self.best_hand_name.as_deref(),
                 all_jokers: &self.jokers,
+                debuffed_jokers: &self.debuffed_jokers,
+                run: &self.run,
             };
 
-            for joker in &self.jokers {
-                let joker_effect = joker_effects.get(&get_joker_id(&joker.joker)).unwrap();
+            for joker_effect in hooks.effects() {
                 if let Some(preferred_scope) =
                     joker_effect.preferred_scoring_scope(&updated_context)
                 {
@@ -92,246 +172,335 @@ impl ScoreManager {
             }
 
             let (base_chips, base_mult) = evaluator.value();
-            self.base_chips = base_chips;
-            self.base_mult = base_mult;
 
-            self.chips = base_chips;
-            self.mult = base_mult;
+            self.chips = BigNum::zero();
+            self.mult = BigNum::zero();
+            self.apply_scoring_ops(&[
+                SourcedOp {
+                    source: OpSource::Hand,
+                    op: ScoreOp::AddChips(BigNum::from(base_chips as i64)),
+                },
+                SourcedOp {
+                    source: OpSource::Hand,
+                    op: ScoreOp::AddMult(BigNum::from(base_mult as i64)),
+                },
+            ]);
+            self.base_chips = self.chips.clone();
+            self.base_mult = self.mult.clone();
 
             let cards_to_score = match scoring_scope {
-                ScoringScope::AllPlayed => &self.cards_played,
-                ScoringScope::BestHand => &self.best_hand_cards,
-                ScoringScope::Custom(ref _cards) => {
-                    panic!("Custom scoring scope not yet supported");
-                }
+                ScoringScope::AllPlayed => self.cards_played.clone(),
+                ScoringScope::BestHand => self.best_hand_cards.clone(),
+                ScoringScope::Custom(_) => return Err(ScoreError::CustomScoringScope),
             };
 
-            for card in cards_to_score {
-                let card_value = card.rank.rank_value();
-                self.chips += card_value;
+            self.run_phase(&hooks, Phase::BeforeScoring, None);
 
-                if let Some(enhancement_type) = &card.enhancement {
-                    let enhancement = modifiers::create_enhancement_handler(enhancement_type);
-                    enhancement.apply(&mut self.chips, &mut self.mult, card, false);
-                }
-
-                if let Some(edition_type) = &card.edition {
-                    let edition = modifiers::create_edition_handler(edition_type);
-                    edition.apply(&mut self.chips, &mut self.mult, card);
+            for card in &cards_to_score {
+                for _ in 0..=seal_retriggers(card) {
+                    let card_ops = card_ops(card, &mut self.rng);
+                    self.apply_scoring_ops(
+                        &SourcedOp::all(OpSource::Card(*card), card_ops).collect::<Vec<_>>(),
+                    );
+                    self.run_phase(&hooks, Phase::CardScored, Some(*card));
                 }
+            }
 
-                let context = JokerContext {
-                    cards_played: &self.cards_played,
-                    cards_in_hand: &self.cards_in_hand,
-                    best_hand_name: self.best_hand_name.as_deref(),
-                    all_jokers: &self.jokers,
-                };
-
-                for joker in &self.jokers {
-                    let joker_effect = joker_effects.get(&get_joker_id(&joker.joker)).unwrap();
-                    if matches!(joker_effect.activation_type(), JokerActivation::OnScored) {
-                        let applied = joker_effect.apply(
-                            &mut self.chips,
-                            &mut self.mult,
-                            Some(card),
-                            &context,
+            for card in self.cards_in_hand.clone() {
+                if let Some(enhancement_type) = &card.enhancement
+                    && matches!(enhancement_type, Enhancement::Steel)
+                {
+                    let enhancement = modifiers::create_enhancement_handler(enhancement_type);
+                    for _ in 0..=seal_retriggers(&card) {
+                        let steel_ops = enhancement.apply(&card, true);
+                        self.apply_scoring_ops(
+                            &SourcedOp::all(OpSource::Card(card), steel_ops).collect::<Vec<_>>(),
                         );
-
-                        if applied && joker_effect.supports_retrigger() {
-                            let card_value = card.rank.rank_value();
-                            self.chips += card_value;
-
-                            if let Some(enhancement_type) = &card.enhancement {
-                                let enhancement =
-                                    modifiers::create_enhancement_handler(enhancement_type);
-                                enhancement.apply(&mut self.chips, &mut self.mult, card, false);
-                            }
-
-                            if let Some(edition_type) = &card.edition {
-                                let edition = modifiers::create_edition_handler(edition_type);
-                                edition.apply(&mut self.chips, &mut self.mult, card);
-                            }
-
-                            for retrigger_joker in &self.jokers {
-                                let retrigger_effect = joker_effects
-                                    .get(&get_joker_id(&retrigger_joker.joker))
-                                    .unwrap();
-                                if matches!(
-                                    retrigger_effect.activation_type(),
-                                    JokerActivation::OnScored
-                                ) {
-                                    retrigger_effect.apply(
-                                        &mut self.chips,
-                                        &mut self.mult,
-                                        Some(card),
-                                        &context,
-                                    );
-                                }
-                            }
-                        }
                     }
                 }
             }
 
-            for card in &self.cards_in_hand {
-                if let Some(enhancement_type) = &card.enhancement {
-                    if matches!(enhancement_type, ortalib::Enhancement::Steel) {
-                        let enhancement = modifiers::create_enhancement_handler(enhancement_type);
-                        enhancement.apply(&mut self.chips, &mut self.mult, card, true);
-                    }
-                }
+            for card in self.cards_in_hand.clone() {
+                self.run_phase(&hooks, Phase::CardHeld, Some(card));
             }
 
-            {
-                let context = JokerContext {
-                    cards_played: &self.cards_played,
-                    cards_in_hand: &self.cards_in_hand,
-                    best_hand_name: self.best_hand_name.as_deref(),
-                    all_jokers: &self.jokers,
-                };
-
-                for card in &self.cards_in_hand {
-                    let mut processed_joker_indices = std::collections::HashSet::new();
-
-                    for (index, joker) in self.jokers.iter().enumerate() {
-                        if !processed_joker_indices.insert(index) {
-                            continue;
-                        }
-
-                        let joker_id = get_joker_id(&joker.joker);
-                        let joker_effect = joker_effects.get(&joker_id).unwrap();
-
-                        if matches!(joker_effect.activation_type(), JokerActivation::OnHeld)
-                            && !matches!(joker.joker, ortalib::Joker::Mime)
-                        {
-                            joker_effect.apply(
-                                &mut self.chips,
-                                &mut self.mult,
-                                Some(card),
-                                &context,
-                            );
-                        }
-                    }
+            self.run_phase(&hooks, Phase::Independent, None);
+            self.run_phase(&hooks, Phase::AfterHand, None);
+        } else if matches!(scoring_scope, ScoringScope::AllPlayed) {
+            self.chips = BigNum::zero();
+            self.mult = BigNum::one();
+        } else {
+            return Ok(BigNum::zero());
+        }
+        if self.run.is_plasma() {
+            self.balance_chips_and_mult();
+        }
+        Ok((&self.chips * &self.mult).floor())
+    }
 
-                    processed_joker_indices.clear();
-                    for (index, joker) in self.jokers.iter().enumerate() {
-                        if !processed_joker_indices.insert(index) {
-                            continue;
-                        }
-
-                        let joker_id = get_joker_id(&joker.joker);
-                        let joker_effect = joker_effects.get(&joker_id).unwrap();
-
-                        if matches!(joker_effect.activation_type(), JokerActivation::OnHeld)
-                            && matches!(joker.joker, ortalib::Joker::Mime)
-                        {
-                            joker_effect.apply(
-                                &mut self.chips,
-                                &mut self.mult,
-                                Some(card),
-                                &context,
-                            );
-                        }
-                    }
+    /// Uses `rng` for chance effects instead of a generator seeded from the round, so a
+    /// sequence of hands and events shares one stream of rolls.
+    pub fn with_rng(mut self, rng: Rng) -> Self {
+        self.rng = rng;
+        self
+    }
 
-                    processed_joker_indices.clear();
-                    for (index, joker) in self.jokers.iter().enumerate() {
-                        if !processed_joker_indices.insert(index) {
-                            continue;
-                        }
-
-                        let joker_id = get_joker_id(&joker.joker);
-                        let joker_effect = joker_effects.get(&joker_id).unwrap();
-
-                        if joker_effect.supports_retrigger()
-                            && matches!(joker_effect.activation_type(), JokerActivation::OnHeld)
-                        {
-                            let retrigger_context = JokerContext {
-                                cards_played: &self.cards_played,
-                                cards_in_hand: &self.cards_in_hand,
-                                best_hand_name: self.best_hand_name.as_deref(),
-                                all_jokers: &self.jokers,
-                            };
-
-                            joker_effect.apply(
-                                &mut self.chips,
-                                &mut self.mult,
-                                Some(card),
-                                &retrigger_context,
-                            );
-                        }
-                    }
+    /// Returns the generator for chance effects, advanced past every roll made so far.
+    pub fn rng(&self) -> &Rng {
+        &self.rng
+    }
+
+    /// Dispatches an event that happens outside a scored hand, such as selecting the blind,
+    /// discarding a card or destroying one, and pays out the money the jokers earn.
+    pub fn dispatch_event(&mut self, phase: Phase, card: Option<Card>) {
+        let hooks = JokerHooks::new(&self.jokers);
+        self.run_phase(&hooks, phase, card);
+    }
+
+    /// Dispatches a phase to the jokers and applies their operations, paying out the money
+    /// they earn during scoring.
+    fn run_phase(&mut self, hooks: &JokerHooks, phase: Phase, card: Option<Card>) {
+        let ops = self.phase_ops(hooks, phase, card);
+        self.apply_scoring_ops(&ops);
+    }
+
+    /// Collects the jokers' operations for a phase, then replays the event's card once for
+    /// every retrigger the jokers returned.
+    fn phase_ops(
+        &mut self,
+        hooks: &JokerHooks,
+        phase: Phase,
+        card: Option<Card>,
+    ) -> Vec<SourcedOp> {
+        let context = JokerContext {
+            cards_played: &self.cards_played,
+            cards_in_hand: &self.cards_in_hand,
+            best_hand_name: self.best_hand_name.as_deref(),
+            all_jokers: &self.jokers,
+            debuffed_jokers: &self.debuffed_jokers,
+            run: &self.run,
+        };
+        let mut event = JokerEvent::new(phase, card.as_ref(), &mut self.rng);
+
+        let mut ops = hooks.dispatch(&mut event, &context);
+        if let Some(card) = card {
+            let retriggers = ops
+                .iter()
+                .filter(|sourced| sourced.op == ScoreOp::Retrigger)
+                .count();
+            for _ in 0..retriggers {
+                if phase == Phase::CardScored {
+                    let card_ops = card_ops(&card, event.rng);
+                    ops.extend(SourcedOp::all(OpSource::Card(card), card_ops));
                 }
+                ops.extend(hooks.replay(&mut event, &context));
             }
+        }
+        ops
+    }
 
-            {
-                let context = JokerContext {
-                    cards_played: &self.cards_played,
-                    cards_in_hand: &self.cards_in_hand,
-                    best_hand_name: self.best_hand_name.as_deref(),
-                    all_jokers: &self.jokers,
-                };
-
-                let mut processed_joker_indices = std::collections::HashSet::new();
-
-                for joker in &self.jokers {
-                    if let Some(edition_type) = &joker.edition {
-                        if matches!(edition_type, ortalib::Edition::Foil)
-                            || matches!(edition_type, ortalib::Edition::Holographic)
-                        {
-                            modifiers::apply_edition_effect(
-                                edition_type,
-                                &mut self.chips,
-                                &mut self.mult,
-                            );
-                        }
-                    }
-                }
+    /// Applies operations to Chips and Mult, paying out their money as earned during scoring.
+    fn apply_scoring_ops(&mut self, ops: &[SourcedOp]) {
+        let earned = self.apply_traced(ops);
+        self.run.money += earned;
+        self.earnings.during_scoring += earned;
+    }
 
-                for (index, joker) in self.jokers.iter().enumerate() {
-                    if !processed_joker_indices.insert(index) {
-                        continue;
-                    }
+    /// Applies operations to Chips and Mult and records them in the trace, returning the
+    /// dollars they earn.
+    fn apply_traced(&mut self, ops: &[SourcedOp]) -> i64 {
+        let mut earned = 0;
+        for sourced in ops {
+            earned += sourced.op.apply(&mut self.chips, &mut self.mult);
+            self.trace.push(TracedOp {
+                source: sourced.source,
+                op: sourced.op.clone(),
+                chips: self.chips.clone(),
+                mult: self.mult.clone(),
+            });
+        }
+        earned
+    }
 
-                    let joker_id = get_joker_id(&joker.joker);
-                    let joker_effect = joker_effects.get(&joker_id).unwrap();
+    /// Sets Chips and Mult to their average, as the Plasma Deck does before multiplying.
+    fn balance_chips_and_mult(&mut self) {
+        let average = &(&self.chips + &self.mult) * &BigNum::new(5, 1);
+        let chips = std::mem::replace(&mut self.chips, average.clone());
+        let mult = std::mem::replace(&mut self.mult, average);
+        self.plasma_balance = Some((chips, mult));
+    }
 
-                    if matches!(joker_effect.activation_type(), JokerActivation::Independent) {
-                        joker_effect.apply(&mut self.chips, &mut self.mult, None, &context);
-                    }
-                }
+    /// Pays out Gold cards held in hand, end of round joker money and interest, charges
+    /// rent for Rental jokers and counts Perishable jokers down.
+    ///
+    /// Only the driver of a round calls this, once, after its last hand; scoring a hand
+    /// never does. Interest is paid on the money held after Gold cards and rent, before
+    /// joker payouts.
+    pub fn end_round(&mut self) {
+        let mimes = self
+            .jokers
+            .iter()
+            .filter(|joker| matches!(joker.joker, Joker::Mime))
+            .count() as i64;
 
-                for joker in &self.jokers {
-                    if let Some(edition_type) = &joker.edition {
-                        if matches!(edition_type, ortalib::Edition::Polychrome) {
-                            modifiers::apply_edition_effect(
-                                edition_type,
-                                &mut self.chips,
-                                &mut self.mult,
-                            );
-                        }
-                    }
-                }
+        let mut earned: i64 = self
+            .cards_in_hand
+            .iter()
+            .filter_map(|card| card.enhancement.as_ref())
+            .map(|enhancement| {
+                modifiers::create_enhancement_handler(enhancement).end_of_round_money()
+                    * (1 + mimes)
+            })
+            .sum();
+
+        let rent = self
+            .jokers
+            .iter()
+            .chain(&self.debuffed_jokers)
+            .filter(|joker| joker.stickers.rental)
+            .count() as i64
+            * RENTAL_COST;
+
+        earned += economy::interest(self.run.money + earned - rent);
+
+        let hooks = JokerHooks::new(&self.jokers);
+        let joker_ops = self.phase_ops(&hooks, Phase::EndOfRound, None);
+        earned += self.apply_traced(&joker_ops);
+
+        self.run.money += earned - rent;
+        self.earnings.end_of_round = earned;
+        self.earnings.rent = rent;
+
+        for joker in &mut self.lineup {
+            joker.stickers.count_down();
+        }
+    }
+
+    /// Returns every joker in slot order, with Perishable stickers counted down once
+    /// `end_round` has run.
+    pub fn lineup(&self) -> &[JokerCard] {
+        &self.lineup
+    }
+
+    /// Returns the run state after the last call to `calculate_score` or `end_round`, with
+    /// money and hand play counts updated.
+    pub fn run_state(&self) -> &RunState {
+        &self.run
+    }
+
+    /// Returns the money earned by `calculate_score` and, once called, `end_round`.
+    pub fn earnings(&self) -> &Earnings {
+        &self.earnings
+    }
+
+    /// Returns every operation applied by the last call to `calculate_score`, in order.
+    pub fn trace(&self) -> &[TracedOp] {
+        &self.trace
+    }
+
+    /// Returns the name of the best hand found by the last call to `calculate_score`.
+    pub fn best_hand_name(&self) -> Option<&str> {
+        self.best_hand_name.as_deref()
+    }
+
+    /// Describes the best hand found and the final score.
+    pub fn explanation(&self, final_score: &BigNum) -> String {
+        let explanation = self.hand_explanation(final_score);
+        if self.debuffed_jokers.is_empty() {
+            return explanation;
+        }
+
+        let skipped: Vec<String> = self
+            .debuffed_jokers
+            .iter()
+            .map(|joker| joker.to_string())
+            .collect();
+        format!(
+            "{}\nSkipped debuffed jokers: {}",
+            explanation,
+            skipped.join(", ")
+        )
+    }
+
+    fn hand_explanation(&self, final_score: &BigNum) -> String {
+        if let Some(ref hand_name) = self.best_hand_name {
+            match &self.plasma_balance {
+                Some((chips, mult)) => format!(
+                    "{} (Plasma Deck balanced {} Chips x {} Mult to {} x {}, Final Score: {})",
+                    hand_name, chips, mult, self.chips, self.mult, final_score
+                ),
+                None => format!("{} (Final Score: {})", hand_name, final_score),
             }
-        } else if matches!(scoring_scope, ScoringScope::AllPlayed) {
-            self.chips = 0.0;
-            self.mult = 1.0;
         } else {
-            return 0.0;
+            "No valid poker hand identified".to_string()
         }
-        (self.chips * self.mult).floor()
     }
 
     /// Computes the score for a round and provides an explanation of the result.
-    pub fn score_with_explanation(round: &Round) -> (Chips, Mult, String) {
+    pub fn score_with_explanation(round: &Round) -> Result<(Chips, Mult, String), ScoreError> {
         let mut manager = ScoreManager::from_round(round);
-        let final_score = manager.calculate_score();
-        let explanation = if let Some(ref hand_name) = manager.best_hand_name {
-            format!("{} (Final Score: {})", hand_name, final_score)
-        } else {
-            "No valid poker hand identified".to_string()
-        };
+        let final_score = manager.calculate_score()?;
+        let explanation = manager.explanation(&final_score);
 
-        (manager.chips, manager.mult, explanation)
+        Ok((manager.chips, manager.mult, explanation))
     }
 }
+
+/// Dispatches `Phase::CardDestroyed` to a round's jokers for each destroyed card, adding
+/// the money they earn to the round.
+pub fn dispatch_destroyed(round: &mut Round, destroyed: &[Card], rng: &mut Rng) {
+    if destroyed.is_empty() {
+        return;
+    }
+
+    let mut manager = ScoreManager::from_round(round).with_rng(rng.clone());
+    for card in destroyed {
+        manager.dispatch_event(Phase::CardDestroyed, Some(*card));
+    }
+    round.run = manager.run_state().clone();
+    *rng = manager.rng().clone();
+}
+
+/// Operations for a scored card: its seal money, rank chips, enhancement, chance effects and
+/// edition.
+fn card_ops(card: &Card, rng: &mut Rng) -> Vec<ScoreOp> {
+    let mut ops = Vec::new();
+
+    let seal_money = seal_money(card);
+    if seal_money != 0 {
+        ops.push(ScoreOp::EarnMoney(seal_money));
+    }
+
+    if !card.is_stone() {
+        ops.push(ScoreOp::AddChips(BigNum::from(
+            card.rank.rank_value() as i64
+        )));
+    }
+
+    if let Some(enhancement_type) = &card.enhancement {
+        let enhancement = modifiers::create_enhancement_handler(enhancement_type);
+        ops.extend(enhancement.apply(card, false));
+        ops.extend(enhancement.apply_chance(rng));
+    }
+
+    if let Some(edition_type) = &card.edition {
+        let edition = modifiers::create_edition_handler(edition_type);
+        ops.extend(edition.apply(card));
+    }
+
+    ops
+}
+
+/// Extra times a card's seal makes it score.
+fn seal_retriggers(card: &Card) -> usize {
+    card.seal
+        .as_ref()
+        .map_or(0, |seal| modifiers::create_seal_handler(seal).retriggers())
+}
+
+/// Dollars a card's seal pays each time the card is scored.
+fn seal_money(card: &Card) -> i64 {
+    card.seal.as_ref().map_or(0, |seal| {
+        modifiers::create_seal_handler(seal).money_on_scored()
+    })
+}
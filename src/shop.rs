@@ -0,0 +1,1050 @@
+use std::{error::Error, fmt};
+
+use serde::Deserialize;
+
+use crate::bignum::BigNum;
+use crate::consumable::{Consumable, Planet, Spectral, Tarot};
+use crate::deck::{ALL_RANKS, ALL_SUITS};
+use crate::model::{
+    Card, DeckVariant, Edition, Enhancement, Joker, JokerCard, Round, Seal, check_joker_slots,
+    lineup_after_round,
+};
+use crate::rng::Rng;
+use crate::score::{ScoreError, ScoreManager};
+use crate::voucher::Voucher;
+
+/// Card slots a shop has before Overstock.
+const BASE_CARD_SLOTS: usize = 2;
+
+/// Booster packs offered by every shop.
+const BOOSTER_PACK_SLOTS: usize = 2;
+
+/// Price of the first reroll in a shop; each reroll after it costs $1 more.
+const BASE_REROLL_COST: i64 = 5;
+
+/// Relative chance of each item type appearing in a card slot.
+const JOKER_RATE: f64 = 20.0;
+const TAROT_RATE: f64 = 4.0;
+const PLANET_RATE: f64 = 4.0;
+
+/// How likely a joker is to appear in the shop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+}
+
+/// Returns the rarity a joker is rolled with.
+pub fn joker_rarity(joker: Joker) -> Rarity {
+    match joker {
+        Joker::Joker
+        | Joker::JollyJoker
+        | Joker::ZanyJoker
+        | Joker::MadJoker
+        | Joker::CrazyJoker
+        | Joker::DrollJoker
+        | Joker::SlyJoker
+        | Joker::WilyJoker
+        | Joker::CleverJoker
+        | Joker::DeviousJoker
+        | Joker::CraftyJoker
+        | Joker::AbstractJoker
+        | Joker::RaisedFist
+        | Joker::GreedyJoker
+        | Joker::LustyJoker
+        | Joker::WrathfulJoker
+        | Joker::GluttonousJoker
+        | Joker::ScaryFace
+        | Joker::EvenSteven
+        | Joker::OddTodd
+        | Joker::Photograph
+        | Joker::SmileyFace
+        | Joker::Splash
+        | Joker::GoldenJoker
+        | Joker::BusinessCard
+        | Joker::Banner
+        | Joker::MysticSummit
+        | Joker::BlueJoker
+        | Joker::Supernova => Rarity::Common,
+        Joker::Blackboard
+        | Joker::Fibonacci
+        | Joker::FlowerPot
+        | Joker::FourFingers
+        | Joker::Shortcut
+        | Joker::Mime
+        | Joker::Pareidolia
+        | Joker::SockAndBuskin
+        | Joker::SmearedJoker
+        | Joker::Bull
+        | Joker::Bootstraps
+        | Joker::RoughGem
+        | Joker::Acrobat
+        | Joker::Dusk
+        | Joker::JokerStencil
+        | Joker::CardSharp => Rarity::Uncommon,
+        Joker::Baron | Joker::Blueprint | Joker::Obelisk => Rarity::Rare,
+    }
+}
+
+/// Returns a joker's shop price before its edition and discounts.
+pub fn joker_base_cost(joker: Joker) -> i64 {
+    match joker {
+        Joker::Joker => 2,
+        Joker::JollyJoker | Joker::SlyJoker | Joker::Splash => 3,
+        Joker::ZanyJoker
+        | Joker::MadJoker
+        | Joker::CrazyJoker
+        | Joker::DrollJoker
+        | Joker::WilyJoker
+        | Joker::CleverJoker
+        | Joker::DeviousJoker
+        | Joker::CraftyJoker
+        | Joker::AbstractJoker
+        | Joker::ScaryFace
+        | Joker::EvenSteven
+        | Joker::OddTodd
+        | Joker::SmileyFace
+        | Joker::BusinessCard => 4,
+        Joker::RaisedFist
+        | Joker::GreedyJoker
+        | Joker::LustyJoker
+        | Joker::WrathfulJoker
+        | Joker::GluttonousJoker
+        | Joker::Photograph
+        | Joker::Mime
+        | Joker::Pareidolia
+        | Joker::Banner
+        | Joker::MysticSummit
+        | Joker::Dusk
+        | Joker::BlueJoker
+        | Joker::Supernova => 5,
+        Joker::Blackboard
+        | Joker::FlowerPot
+        | Joker::SockAndBuskin
+        | Joker::GoldenJoker
+        | Joker::Bull
+        | Joker::Acrobat
+        | Joker::CardSharp => 6,
+        Joker::FourFingers
+        | Joker::Shortcut
+        | Joker::SmearedJoker
+        | Joker::Bootstraps
+        | Joker::RoughGem => 7,
+        Joker::Baron | Joker::Fibonacci | Joker::JokerStencil | Joker::Obelisk => 8,
+        Joker::Blueprint => 10,
+    }
+}
+
+/// Extra price of a joker's edition.
+fn edition_cost(edition: Option<Edition>) -> i64 {
+    match edition {
+        Some(Edition::Foil) => 2,
+        Some(Edition::Holographic) => 3,
+        Some(Edition::Polychrome) | Some(Edition::Negative) => 5,
+        None => 0,
+    }
+}
+
+/// What a booster pack contains.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PackKind {
+    Arcana,
+    Celestial,
+    Buffoon,
+    Standard,
+    Spectral,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PackSize {
+    Normal,
+    Jumbo,
+    Mega,
+}
+
+/// Every booster pack with its relative chance of being offered.
+const PACK_WEIGHTS: [(PackKind, PackSize, f64); 15] = [
+    (PackKind::Arcana, PackSize::Normal, 4.0),
+    (PackKind::Arcana, PackSize::Jumbo, 2.0),
+    (PackKind::Arcana, PackSize::Mega, 0.5),
+    (PackKind::Celestial, PackSize::Normal, 4.0),
+    (PackKind::Celestial, PackSize::Jumbo, 2.0),
+    (PackKind::Celestial, PackSize::Mega, 0.5),
+    (PackKind::Standard, PackSize::Normal, 4.0),
+    (PackKind::Standard, PackSize::Jumbo, 2.0),
+    (PackKind::Standard, PackSize::Mega, 0.5),
+    (PackKind::Buffoon, PackSize::Normal, 1.2),
+    (PackKind::Buffoon, PackSize::Jumbo, 0.6),
+    (PackKind::Buffoon, PackSize::Mega, 0.15),
+    (PackKind::Spectral, PackSize::Normal, 0.6),
+    (PackKind::Spectral, PackSize::Jumbo, 0.3),
+    (PackKind::Spectral, PackSize::Mega, 0.07),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoosterPack {
+    pub kind: PackKind,
+    pub size: PackSize,
+}
+
+impl BoosterPack {
+    pub fn base_cost(&self) -> i64 {
+        match self.size {
+            PackSize::Normal => 4,
+            PackSize::Jumbo => 6,
+            PackSize::Mega => 8,
+        }
+    }
+
+    /// Number of cards revealed when the pack is opened.
+    pub fn cards_shown(&self) -> usize {
+        match (self.kind, self.size) {
+            (PackKind::Buffoon | PackKind::Spectral, PackSize::Normal) => 2,
+            (PackKind::Buffoon | PackKind::Spectral, _) => 4,
+            (_, PackSize::Normal) => 3,
+            (_, _) => 5,
+        }
+    }
+
+    /// Number of revealed cards that may be taken.
+    pub fn choices(&self) -> usize {
+        match self.size {
+            PackSize::Mega => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl fmt::Display for BoosterPack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = match self.size {
+            PackSize::Normal => "",
+            PackSize::Jumbo => "Jumbo ",
+            PackSize::Mega => "Mega ",
+        };
+        write!(f, "{}{:?} Pack", size, self.kind)
+    }
+}
+
+/// Something that can be bought in a card slot or taken from a booster pack.
+#[derive(Clone, Copy, Debug)]
+pub enum ShopItem {
+    Joker(JokerCard),
+    Consumable(Consumable),
+    PlayingCard(Card),
+}
+
+impl fmt::Display for ShopItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShopItem::Joker(joker) => write!(f, "{}", joker),
+            ShopItem::Consumable(consumable) => write!(f, "{}", consumable),
+            ShopItem::PlayingCard(card) => write!(f, "{}", card),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ShopError {
+    NotEnoughMoney { cost: i64, money: i64 },
+    InvalidIndex(usize),
+    NoJokerSlot,
+    NoConsumableSlot,
+    VoucherNotOffered(Voucher),
+    EternalJoker(JokerCard),
+    TooManyChoices { chosen: usize, allowed: usize },
+    DuplicateChoice(usize),
+}
+
+impl fmt::Display for ShopError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShopError::NotEnoughMoney { cost, money } => {
+                write!(f, "costs ${} but only ${} is available", cost, money)
+            }
+            ShopError::InvalidIndex(index) => write!(f, "index {} is out of range", index),
+            ShopError::NoJokerSlot => write!(f, "no free joker slot"),
+            ShopError::NoConsumableSlot => write!(f, "no free consumable slot"),
+            ShopError::VoucherNotOffered(voucher) => {
+                write!(f, "{} is not offered in this shop", voucher)
+            }
+            ShopError::EternalJoker(joker) => write!(f, "{} cannot be sold", joker),
+            ShopError::TooManyChoices { chosen, allowed } => {
+                write!(f, "{} cards chosen, at most {} allowed", chosen, allowed)
+            }
+            ShopError::DuplicateChoice(index) => write!(f, "card {} chosen twice", index),
+        }
+    }
+}
+
+impl Error for ShopError {}
+
+/// The shop between two blinds, together with the player's money and collection.
+pub struct Shop {
+    rng: Rng,
+    pub money: i64,
+    pub jokers: Vec<JokerCard>,
+    pub consumables: Vec<Consumable>,
+    pub vouchers: Vec<Voucher>,
+    pub joker_slots: usize,
+    pub consumable_slots: usize,
+    /// Playing cards taken from Standard packs, to be added to the deck.
+    pub deck_additions: Vec<Card>,
+    pub cards: Vec<ShopItem>,
+    pub packs: Vec<BoosterPack>,
+    pub voucher: Option<Voucher>,
+    rerolls: i64,
+}
+
+impl Shop {
+    /// Opens a shop for the player described by `input`, rolling its stock from the seed.
+    pub fn new(input: &ShopInput) -> Self {
+        let mut shop = Shop {
+            rng: Rng::new(input.seed),
+            money: input.money,
+            jokers: input.jokers.clone(),
+            consumables: input.consumables.clone(),
+            vouchers: Vec::new(),
+            joker_slots: input.joker_slots.saturating_sub(
+                input
+                    .deck_variant
+                    .map_or(0, |variant| variant.joker_slot_penalty()),
+            ),
+            consumable_slots: input.consumable_slots,
+            deck_additions: Vec::new(),
+            cards: Vec::new(),
+            packs: Vec::new(),
+            voucher: None,
+            rerolls: 0,
+        };
+        if input.deck_variant == Some(DeckVariant::Zodiac) {
+            for voucher in [
+                Voucher::TarotMerchant,
+                Voucher::PlanetMerchant,
+                Voucher::Overstock,
+            ] {
+                shop.apply_voucher(voucher);
+            }
+        }
+        for voucher in &input.vouchers {
+            if !shop.has_voucher(*voucher) {
+                shop.apply_voucher(*voucher);
+            }
+        }
+
+        shop.restock_cards();
+        for _ in 0..BOOSTER_PACK_SLOTS {
+            let pack = shop.roll_pack();
+            shop.packs.push(pack);
+        }
+        shop.voucher = shop.roll_voucher();
+        shop
+    }
+
+    pub fn has_voucher(&self, voucher: Voucher) -> bool {
+        self.vouchers.contains(&voucher)
+    }
+
+    pub fn card_slots(&self) -> usize {
+        BASE_CARD_SLOTS
+            + self.has_voucher(Voucher::Overstock) as usize
+            + self.has_voucher(Voucher::OverstockPlus) as usize
+    }
+
+    pub fn reroll_cost(&self) -> i64 {
+        let discount = 2
+            * (self.has_voucher(Voucher::RerollSurplus) as i64
+                + self.has_voucher(Voucher::RerollGlut) as i64);
+        (BASE_REROLL_COST - discount + self.rerolls).max(0)
+    }
+
+    /// Applies Clearance Sale and Liquidation to a base price, rounding half a dollar up
+    /// before the discount and down after it as the game does, so $3 at 50% off is $1.
+    pub fn price(&self, base_cost: i64) -> i64 {
+        let discount = if self.has_voucher(Voucher::Liquidation) {
+            50
+        } else if self.has_voucher(Voucher::ClearanceSale) {
+            25
+        } else {
+            0
+        };
+        ((2 * base_cost + 1) * (100 - discount) / 200).max(1)
+    }
+
+    /// Price of an item. Rental jokers always cost $1.
+    pub fn item_price(&self, item: &ShopItem) -> i64 {
+        match item {
+            ShopItem::Joker(joker) if joker.stickers.rental => 1,
+            ShopItem::Joker(joker) => {
+                self.price(joker_base_cost(joker.joker) + edition_cost(joker.edition))
+            }
+            ShopItem::Consumable(consumable) => self.price(consumable.base_cost()),
+            ShopItem::PlayingCard(_) => self.price(1),
+        }
+    }
+
+    /// Money received for selling an item: half its price, rounded down, at least $1.
+    pub fn sell_value(&self, item: &ShopItem) -> i64 {
+        (self.item_price(item) / 2).max(1)
+    }
+
+    /// Buys the item in the given card slot.
+    pub fn buy(&mut self, index: usize) -> Result<String, ShopError> {
+        let item = *self
+            .cards
+            .get(index)
+            .ok_or(ShopError::InvalidIndex(index))?;
+        let cost = self.item_price(&item);
+        self.check_affordable(cost)?;
+        self.take(item)?;
+
+        self.money -= cost;
+        self.cards.remove(index);
+        Ok(format!(
+            "Bought {} for ${} (${} left)",
+            item, cost, self.money
+        ))
+    }
+
+    /// Sells the joker at the given position.
+    pub fn sell_joker(&mut self, index: usize) -> Result<String, ShopError> {
+        let joker = *self
+            .jokers
+            .get(index)
+            .ok_or(ShopError::InvalidIndex(index))?;
+        if joker.stickers.eternal {
+            return Err(ShopError::EternalJoker(joker));
+        }
+        self.jokers.remove(index);
+        let value = self.sell_value(&ShopItem::Joker(joker));
+        self.money += value;
+        Ok(format!(
+            "Sold {} for ${} (${} left)",
+            joker, value, self.money
+        ))
+    }
+
+    /// Sells the consumable at the given position.
+    pub fn sell_consumable(&mut self, index: usize) -> Result<String, ShopError> {
+        if index >= self.consumables.len() {
+            return Err(ShopError::InvalidIndex(index));
+        }
+        let consumable = self.consumables.remove(index);
+        let value = self.sell_value(&ShopItem::Consumable(consumable));
+        self.money += value;
+        Ok(format!(
+            "Sold {} for ${} (${} left)",
+            consumable, value, self.money
+        ))
+    }
+
+    /// Replaces every card slot with new stock.
+    pub fn reroll(&mut self) -> Result<String, ShopError> {
+        let cost = self.reroll_cost();
+        self.check_affordable(cost)?;
+
+        self.money -= cost;
+        self.rerolls += 1;
+        self.cards.clear();
+        self.restock_cards();
+        Ok(format!(
+            "Rerolled for ${} (${} left): {}",
+            cost,
+            self.money,
+            self.describe_cards()
+        ))
+    }
+
+    /// Buys and redeems the offered voucher.
+    pub fn redeem(&mut self, voucher: Voucher) -> Result<String, ShopError> {
+        if self.voucher != Some(voucher) {
+            return Err(ShopError::VoucherNotOffered(voucher));
+        }
+        let cost = self.price(voucher.base_cost());
+        self.check_affordable(cost)?;
+
+        self.money -= cost;
+        self.voucher = None;
+        self.apply_voucher(voucher);
+        self.restock_cards();
+        Ok(format!(
+            "Redeemed {} for ${} (${} left)",
+            voucher, cost, self.money
+        ))
+    }
+
+    /// Buys the booster pack in the given slot and takes the chosen cards from it.
+    ///
+    /// Tarot and planet cards taken from packs are kept as consumables rather than
+    /// used straight away.
+    pub fn open_pack(&mut self, index: usize, choose: &[usize]) -> Result<String, ShopError> {
+        let pack = *self
+            .packs
+            .get(index)
+            .ok_or(ShopError::InvalidIndex(index))?;
+        if choose.len() > pack.choices() {
+            return Err(ShopError::TooManyChoices {
+                chosen: choose.len(),
+                allowed: pack.choices(),
+            });
+        }
+        if let Some(&invalid) = choose.iter().find(|&&i| i >= pack.cards_shown()) {
+            return Err(ShopError::InvalidIndex(invalid));
+        }
+        if let Some((_, &duplicate)) = choose
+            .iter()
+            .enumerate()
+            .find(|&(position, i)| choose[..position].contains(i))
+        {
+            return Err(ShopError::DuplicateChoice(duplicate));
+        }
+        let cost = self.price(pack.base_cost());
+        self.check_affordable(cost)?;
+
+        // Everything chosen must fit before the pack is paid for, so a failed open leaves
+        // the shop, including its random state, as it was.
+        let rng = self.rng.clone();
+        let contents = self.roll_pack_contents(pack);
+        let chosen: Vec<ShopItem> = choose.iter().map(|&i| contents[i]).collect();
+        if let Err(error) = self.check_room(&chosen) {
+            self.rng = rng;
+            return Err(error);
+        }
+
+        self.money -= cost;
+        self.packs.remove(index);
+        let mut taken = Vec::new();
+        for item in chosen {
+            self.take(item)?;
+            taken.push(item.to_string());
+        }
+
+        let shown: Vec<String> = contents.iter().map(|item| item.to_string()).collect();
+        Ok(format!(
+            "Opened {} for ${} (${} left): [{}], took [{}]",
+            pack,
+            cost,
+            self.money,
+            shown.join(", "),
+            taken.join(", ")
+        ))
+    }
+
+    /// Settles the round played before the shop, as cashing out does: interest and end of
+    /// round joker money are paid, rent is charged and Perishable jokers count down.
+    pub fn end_round(&mut self) -> String {
+        let mut round = Round::new(Vec::new(), Vec::new(), self.jokers.clone());
+        round.run.money = self.money;
+        let mut manager = ScoreManager::from_round(&round);
+        manager.end_round();
+
+        self.money = manager.run_state().money;
+        self.jokers = manager.lineup().to_vec();
+        let mut line = format!("Round settled. {}", manager.earnings());
+        if let Some(lineup) = lineup_after_round(&self.jokers) {
+            line.push('\n');
+            line.push_str(&lineup);
+        }
+        line
+    }
+
+    /// Lists the card slots with their prices.
+    pub fn describe_cards(&self) -> String {
+        let cards: Vec<String> = self
+            .cards
+            .iter()
+            .map(|item| format!("{} (${})", item, self.item_price(item)))
+            .collect();
+        cards.join(", ")
+    }
+
+    fn check_affordable(&self, cost: i64) -> Result<(), ShopError> {
+        if cost > self.money {
+            return Err(ShopError::NotEnoughMoney {
+                cost,
+                money: self.money,
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds a bought or chosen item to the player's collection.
+    /// Checks that the items fit in the free joker and consumable slots together.
+    fn check_room(&self, items: &[ShopItem]) -> Result<(), ShopError> {
+        let mut lineup = self.jokers.clone();
+        let mut consumables = self.consumables.len();
+        for item in items {
+            match item {
+                ShopItem::Joker(joker) => {
+                    lineup.push(*joker);
+                    if check_joker_slots(&lineup, self.joker_slots).is_err() {
+                        return Err(ShopError::NoJokerSlot);
+                    }
+                }
+                ShopItem::Consumable(_) => {
+                    consumables += 1;
+                    if consumables > self.consumable_slots {
+                        return Err(ShopError::NoConsumableSlot);
+                    }
+                }
+                ShopItem::PlayingCard(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn take(&mut self, item: ShopItem) -> Result<(), ShopError> {
+        match item {
+            ShopItem::Joker(joker) => {
+                let mut lineup = self.jokers.clone();
+                lineup.push(joker);
+                if check_joker_slots(&lineup, self.joker_slots).is_err() {
+                    return Err(ShopError::NoJokerSlot);
+                }
+                self.jokers.push(joker);
+            }
+            ShopItem::Consumable(consumable) => {
+                if self.consumables.len() >= self.consumable_slots {
+                    return Err(ShopError::NoConsumableSlot);
+                }
+                self.consumables.push(consumable);
+            }
+            ShopItem::PlayingCard(card) => self.deck_additions.push(card),
+        }
+        Ok(())
+    }
+
+    fn apply_voucher(&mut self, voucher: Voucher) {
+        match voucher {
+            Voucher::CrystalBall => self.consumable_slots += 1,
+            Voucher::Antimatter => self.joker_slots += 1,
+            _ => {}
+        }
+        self.vouchers.push(voucher);
+    }
+
+    /// Fills empty card slots.
+    fn restock_cards(&mut self) {
+        while self.cards.len() < self.card_slots() {
+            let item = self.roll_card();
+            self.cards.push(item);
+        }
+    }
+
+    fn roll_card(&mut self) -> ShopItem {
+        let tarot_rate = TAROT_RATE * self.frequency(Voucher::TarotMerchant, Voucher::TarotTycoon);
+        let planet_rate =
+            PLANET_RATE * self.frequency(Voucher::PlanetMerchant, Voucher::PlanetTycoon);
+
+        let roll = self.rng.next_f64() * (JOKER_RATE + tarot_rate + planet_rate);
+        if roll < JOKER_RATE {
+            ShopItem::Joker(self.roll_joker())
+        } else if roll < JOKER_RATE + tarot_rate {
+            ShopItem::Consumable(Consumable::Tarot(self.roll_tarots(1)[0]))
+        } else {
+            ShopItem::Consumable(Consumable::Planet(self.roll_planets(1)[0]))
+        }
+    }
+
+    /// Rate multiplier from a pair of merchant and tycoon vouchers.
+    fn frequency(&self, merchant: Voucher, tycoon: Voucher) -> f64 {
+        if self.has_voucher(tycoon) {
+            8.0
+        } else if self.has_voucher(merchant) {
+            2.4
+        } else {
+            1.0
+        }
+    }
+
+    /// Rolls a joker the player does not already own or see in the shop, if possible.
+    ///
+    /// Stickers are never rolled, since they depend on a stake the shop does not know.
+    fn roll_joker(&mut self) -> JokerCard {
+        let roll = self.rng.next_f64();
+        let rarity = if roll < 0.7 {
+            Rarity::Common
+        } else if roll < 0.95 {
+            Rarity::Uncommon
+        } else {
+            Rarity::Rare
+        };
+
+        let of_rarity: Vec<Joker> = Joker::ALL
+            .iter()
+            .copied()
+            .filter(|&joker| joker_rarity(joker) == rarity)
+            .collect();
+        let unseen: Vec<Joker> = of_rarity
+            .iter()
+            .copied()
+            .filter(|&joker| !self.owns_or_offers(joker))
+            .collect();
+        let pool = if unseen.is_empty() { of_rarity } else { unseen };
+
+        let joker = pool[self.rng.below(pool.len())];
+        let rate = if self.has_voucher(Voucher::GlowUp) {
+            4.0
+        } else if self.has_voucher(Voucher::Hone) {
+            2.0
+        } else {
+            1.0
+        };
+        let edition = self.roll_edition(rate, true);
+        JokerCard::new(joker, edition)
+    }
+
+    fn owns_or_offers(&self, joker: Joker) -> bool {
+        self.jokers.iter().any(|owned| owned.joker == joker)
+            || self
+                .cards
+                .iter()
+                .any(|item| matches!(item, ShopItem::Joker(offered) if offered.joker == joker))
+    }
+
+    /// Rolls an edition, with `rate` scaling the base chance of each one except Negative,
+    /// which only jokers can have and always at 0.3%.
+    fn roll_edition(&mut self, rate: f64, negative: bool) -> Option<Edition> {
+        let roll = self.rng.next_f64();
+        if negative && roll > 1.0 - 0.003 {
+            Some(Edition::Negative)
+        } else if roll > 1.0 - 0.006 * rate {
+            Some(Edition::Polychrome)
+        } else if roll > 1.0 - 0.02 * rate {
+            Some(Edition::Holographic)
+        } else if roll > 1.0 - 0.04 * rate {
+            Some(Edition::Foil)
+        } else {
+            None
+        }
+    }
+
+    /// Picks `count` distinct tarot cards.
+    fn roll_tarots(&mut self, count: usize) -> Vec<Tarot> {
+        let mut tarots = Tarot::ALL.to_vec();
+        self.rng.partial_shuffle(&mut tarots, count);
+        tarots.truncate(count);
+        tarots
+    }
+
+    /// Picks `count` distinct planet cards, leaving out secret planets.
+    fn roll_planets(&mut self, count: usize) -> Vec<Planet> {
+        let mut planets: Vec<Planet> = Planet::ALL
+            .iter()
+            .copied()
+            .filter(|planet| !planet.is_secret())
+            .collect();
+        self.rng.partial_shuffle(&mut planets, count);
+        planets.truncate(count);
+        planets
+    }
+
+    /// Picks `count` distinct spectral cards.
+    fn roll_spectrals(&mut self, count: usize) -> Vec<Spectral> {
+        let mut spectrals = Spectral::ALL.to_vec();
+        self.rng.partial_shuffle(&mut spectrals, count);
+        spectrals.truncate(count);
+        spectrals
+    }
+
+    fn roll_playing_card(&mut self) -> Card {
+        let rank = ALL_RANKS[self.rng.below(ALL_RANKS.len())];
+        let suit = ALL_SUITS[self.rng.below(ALL_SUITS.len())];
+
+        let enhancement = if self.rng.next_f64() < 0.4 {
+            Some(Enhancement::ALL[self.rng.below(Enhancement::ALL.len())])
+        } else {
+            None
+        };
+        let edition = self.roll_edition(2.0, false);
+        let seal = if self.rng.next_f64() < 0.2 {
+            Some(Seal::ALL[self.rng.below(Seal::ALL.len())])
+        } else {
+            None
+        };
+        Card::new(rank, suit, enhancement, edition).with_seal(seal)
+    }
+
+    fn roll_pack(&mut self) -> BoosterPack {
+        let total: f64 = PACK_WEIGHTS.iter().map(|(_, _, weight)| weight).sum();
+        let mut roll = self.rng.next_f64() * total;
+        for (kind, size, weight) in PACK_WEIGHTS {
+            if roll < weight {
+                return BoosterPack { kind, size };
+            }
+            roll -= weight;
+        }
+        BoosterPack {
+            kind: PackKind::Arcana,
+            size: PackSize::Normal,
+        }
+    }
+
+    fn roll_pack_contents(&mut self, pack: BoosterPack) -> Vec<ShopItem> {
+        let count = pack.cards_shown();
+        match pack.kind {
+            PackKind::Arcana => self
+                .roll_tarots(count)
+                .into_iter()
+                .map(|tarot| ShopItem::Consumable(Consumable::Tarot(tarot)))
+                .collect(),
+            PackKind::Celestial => self
+                .roll_planets(count)
+                .into_iter()
+                .map(|planet| ShopItem::Consumable(Consumable::Planet(planet)))
+                .collect(),
+            PackKind::Buffoon => (0..count)
+                .map(|_| ShopItem::Joker(self.roll_joker()))
+                .collect(),
+            PackKind::Standard => (0..count)
+                .map(|_| ShopItem::PlayingCard(self.roll_playing_card()))
+                .collect(),
+            PackKind::Spectral => self
+                .roll_spectrals(count)
+                .into_iter()
+                .map(|spectral| ShopItem::Consumable(Consumable::Spectral(spectral)))
+                .collect(),
+        }
+    }
+
+    /// Picks a voucher that has not been redeemed and whose prerequisite has been.
+    fn roll_voucher(&mut self) -> Option<Voucher> {
+        let available: Vec<Voucher> = Voucher::ALL
+            .iter()
+            .copied()
+            .filter(|voucher| !self.has_voucher(*voucher))
+            .filter(|voucher| voucher.requires().is_none_or(|req| self.has_voucher(req)))
+            .collect();
+        if available.is_empty() {
+            return None;
+        }
+        Some(available[self.rng.below(available.len())])
+    }
+}
+
+/// One step taken in the shop.
+///
+/// Written in YAML as `- buy: 0`, `- sell: 1`, `- sell_consumable: 0`, `- reroll: 2`,
+/// `- redeem: Overstock` or `- open_pack: 0` with an optional `choose: [1]`.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum ShopAction {
+    OpenPack {
+        open_pack: usize,
+        #[serde(default)]
+        choose: Vec<usize>,
+    },
+    Buy {
+        buy: usize,
+    },
+    Sell {
+        sell: usize,
+    },
+    SellConsumable {
+        sell_consumable: usize,
+    },
+    Reroll {
+        reroll: u32,
+    },
+    Redeem {
+        redeem: Voucher,
+    },
+}
+
+/// The player's situation on entering the shop and the moves to make there.
+#[derive(Deserialize)]
+pub struct ShopInput {
+    #[serde(default)]
+    pub money: i64,
+
+    #[serde(default)]
+    pub seed: u64,
+
+    #[serde(default)]
+    pub jokers: Vec<JokerCard>,
+
+    #[serde(default)]
+    pub consumables: Vec<Consumable>,
+
+    /// Vouchers already redeemed. Their slot bonuses are added to the slot counts below.
+    #[serde(default)]
+    pub vouchers: Vec<Voucher>,
+
+    #[serde(default = "default_joker_slots")]
+    pub joker_slots: usize,
+
+    /// The Zodiac Deck starts with Tarot Merchant, Planet Merchant and Overstock; the
+    /// Painted Deck takes away a joker slot.
+    #[serde(default)]
+    pub deck_variant: Option<DeckVariant>,
+
+    #[serde(default = "default_consumable_slots")]
+    pub consumable_slots: usize,
+
+    /// Settle the round just played before shopping, with `money` as held before cashing
+    /// out.
+    #[serde(default)]
+    pub end_round: bool,
+
+    #[serde(default)]
+    pub actions: Vec<ShopAction>,
+
+    /// A hand to score with the jokers held before and after shopping.
+    #[serde(default)]
+    pub evaluate: Option<Round>,
+}
+
+fn default_joker_slots() -> usize {
+    5
+}
+
+fn default_consumable_slots() -> usize {
+    2
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ShopActionError {
+    pub action: usize,
+    pub error: ShopError,
+}
+
+impl fmt::Display for ShopActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "action {}: {}", self.action + 1, self.error)
+    }
+}
+
+impl Error for ShopActionError {}
+
+/// Why a shop script could not be run to the end.
+#[derive(Debug, PartialEq)]
+pub enum ShopRunError {
+    Action(ShopActionError),
+    /// The hand to evaluate after shopping could not be scored.
+    Evaluate(ScoreError),
+}
+
+impl fmt::Display for ShopRunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShopRunError::Action(error) => write!(f, "{}", error),
+            ShopRunError::Evaluate(error) => write!(f, "evaluate: {}", error),
+        }
+    }
+}
+
+impl Error for ShopRunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ShopRunError::Action(error) => Some(error),
+            ShopRunError::Evaluate(error) => Some(error),
+        }
+    }
+}
+
+/// What happened in the shop and what the player left with.
+pub struct ShopReport {
+    pub log: Vec<String>,
+    pub shop: Shop,
+    /// Score of the evaluated hand before and after shopping.
+    pub evaluation: Option<(BigNum, BigNum)>,
+}
+
+impl fmt::Display for ShopReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.log {
+            writeln!(f, "{}", line)?;
+        }
+
+        let shop = &self.shop;
+        let jokers: Vec<String> = shop.jokers.iter().map(|j| j.to_string()).collect();
+        let consumables: Vec<String> = shop.consumables.iter().map(|c| c.to_string()).collect();
+        let vouchers: Vec<String> = shop.vouchers.iter().map(|v| v.to_string()).collect();
+        write!(
+            f,
+            "Leaving with ${}\nJokers: [{}]\nConsumables: [{}]\nVouchers: [{}]",
+            shop.money,
+            jokers.join(", "),
+            consumables.join(", "),
+            vouchers.join(", ")
+        )?;
+        if !shop.deck_additions.is_empty() {
+            let cards: Vec<String> = shop.deck_additions.iter().map(|c| c.to_string()).collect();
+            write!(f, "\nAdded to deck: [{}]", cards.join(", "))?;
+        }
+        if let Some((before, after)) = &self.evaluation {
+            write!(f, "\nHand score: {} before, {} after", before, after)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs a shop script from start to finish.
+pub fn simulate_shop(input: &ShopInput) -> Result<ShopReport, ShopRunError> {
+    let mut shop = Shop::new(input);
+    let mut log = Vec::new();
+    if input.end_round {
+        log.push(shop.end_round());
+    }
+
+    let packs: Vec<String> = shop
+        .packs
+        .iter()
+        .map(|pack| format!("{} (${})", pack, shop.price(pack.base_cost())))
+        .collect();
+    log.push(format!("Cards: {}", shop.describe_cards()));
+    log.push(format!("Packs: {}", packs.join(", ")));
+    if let Some(voucher) = shop.voucher {
+        log.push(format!(
+            "Voucher: {} (${})",
+            voucher,
+            shop.price(voucher.base_cost())
+        ));
+    }
+
+    for (action_index, action) in input.actions.iter().enumerate() {
+        let result = match action {
+            ShopAction::Buy { buy } => shop.buy(*buy),
+            ShopAction::Sell { sell } => shop.sell_joker(*sell),
+            ShopAction::SellConsumable { sell_consumable } => {
+                shop.sell_consumable(*sell_consumable)
+            }
+            ShopAction::Reroll { reroll } => (0..*reroll)
+                .map(|_| shop.reroll())
+                .collect::<Result<Vec<_>, _>>()
+                .map(|lines| lines.join("\n")),
+            ShopAction::Redeem { redeem } => shop.redeem(*redeem),
+            ShopAction::OpenPack { open_pack, choose } => shop.open_pack(*open_pack, choose),
+        };
+        let line = result.map_err(|error| {
+            ShopRunError::Action(ShopActionError {
+                action: action_index,
+                error,
+            })
+        })?;
+        log.push(line);
+    }
+
+    let evaluation = match &input.evaluate {
+        Some(round) => {
+            let score_with = |jokers: &[JokerCard]| {
+                let round = Round {
+                    jokers: jokers.to_vec(),
+                    ..round.clone()
+                };
+                ScoreManager::from_round(&round)
+                    .calculate_score()
+                    .map_err(ShopRunError::Evaluate)
+            };
+            Some((score_with(&input.jokers)?, score_with(&shop.jokers)?))
+        }
+        None => None,
+    };
+
+    Ok(ShopReport {
+        log,
+        shop,
+        evaluation,
+    })
+}
@@ -0,0 +1,507 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::consumable::Spectral;
+use crate::deck::{ALL_RANKS, ALL_SUITS};
+use crate::model::{Card, Edition, Enhancement, JokerCard, Rank, Round, Seal};
+use crate::rng::Rng;
+use crate::score;
+
+/// Enhancements given to the cards Familiar, Grim and Incantation create: any but Stone.
+const CREATED_CARD_ENHANCEMENTS: [Enhancement; 7] = [
+    Enhancement::Bonus,
+    Enhancement::Mult,
+    Enhancement::Wild,
+    Enhancement::Glass,
+    Enhancement::Steel,
+    Enhancement::Gold,
+    Enhancement::Lucky,
+];
+
+/// The cards, jokers and money a spectral card can change.
+///
+/// `cards` holds the played cards followed by the cards held in hand.
+pub struct SpectralTarget {
+    pub cards: Vec<Card>,
+    pub played: usize,
+    pub jokers: Vec<JokerCard>,
+    pub money: i64,
+    /// Cards destroyed since the jokers were last told about them.
+    pub destroyed: Vec<Card>,
+}
+
+impl SpectralTarget {
+    pub fn from_round(round: &Round) -> Self {
+        let mut cards = round.cards_played.clone();
+        cards.extend(round.cards_held_in_hand.iter().copied());
+        SpectralTarget {
+            cards,
+            played: round.cards_played.len(),
+            jokers: round.jokers.clone(),
+            money: round.run.money,
+            destroyed: Vec::new(),
+        }
+    }
+
+    /// Writes the changed cards, jokers and money back into a round.
+    pub fn write_to(&self, round: &mut Round) {
+        round.cards_played = self.cards[..self.played].to_vec();
+        round.cards_held_in_hand = self.cards[self.played..].to_vec();
+        round.jokers = self.jokers.clone();
+        round.run.money = self.money;
+    }
+
+    fn destroy_card(&mut self, index: usize) {
+        if index < self.played {
+            self.played -= 1;
+        }
+        self.destroyed.push(self.cards.remove(index));
+    }
+
+    fn destroy_random_cards(&mut self, count: usize, rng: &mut Rng) {
+        for _ in 0..count.min(self.cards.len()) {
+            let index = rng.below(self.cards.len());
+            self.destroy_card(index);
+        }
+    }
+
+    /// Adds new cards to the hand with random suits and enhancements other than Stone.
+    fn add_enhanced_cards(&mut self, ranks: &[Rank], count: usize, rng: &mut Rng) {
+        for _ in 0..count {
+            let rank = ranks[rng.below(ranks.len())];
+            let suit = ALL_SUITS[rng.below(ALL_SUITS.len())];
+            let enhancement = CREATED_CARD_ENHANCEMENTS[rng.below(CREATED_CARD_ENHANCEMENTS.len())];
+            self.cards
+                .push(Card::new(rank, suit, Some(enhancement), None));
+        }
+    }
+
+    /// Destroys every joker except the one at `index` and Eternal ones.
+    fn destroy_jokers_except(&mut self, index: usize) {
+        let mut position = 0;
+        self.jokers.retain(|joker| {
+            let keep = position == index || joker.stickers.eternal;
+            position += 1;
+            keep
+        });
+    }
+
+    /// Picks a random joker without an edition.
+    fn random_plain_joker(&self, rng: &mut Rng) -> Option<usize> {
+        let plain: Vec<usize> = (0..self.jokers.len())
+            .filter(|&index| self.jokers[index].edition.is_none())
+            .collect();
+        if plain.is_empty() {
+            return None;
+        }
+        Some(plain[rng.below(plain.len())])
+    }
+}
+
+pub trait SpectralEffect {
+    /// Fewest and most cards that can be selected.
+    fn selection(&self) -> (usize, usize) {
+        (0, 0)
+    }
+    /// Applies the effect, with the selected cards given in ascending order.
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError>;
+}
+
+/// Familiar, Grim and Incantation destroy a random card and add enhanced cards.
+pub struct ReplaceCardSpectral {
+    ranks: &'static [Rank],
+    count: usize,
+}
+
+impl SpectralEffect for ReplaceCardSpectral {
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        _selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        target.destroy_random_cards(1, rng);
+        target.add_enhanced_cards(self.ranks, self.count, rng);
+        Ok(())
+    }
+}
+
+/// Talisman, Deja Vu, Trance and Medium add a seal to one card.
+pub struct SealSpectral {
+    seal: Seal,
+}
+
+impl SpectralEffect for SealSpectral {
+    fn selection(&self) -> (usize, usize) {
+        (1, 1)
+    }
+
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        selected: &[usize],
+        _rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        target.cards[selected[0]].seal = Some(self.seal);
+        Ok(())
+    }
+}
+
+/// Aura adds Foil, Holographic or Polychrome to one card.
+pub struct AuraSpectral;
+
+impl SpectralEffect for AuraSpectral {
+    fn selection(&self) -> (usize, usize) {
+        (1, 1)
+    }
+
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        let roll = rng.next_f64();
+        let edition = if roll < 0.5 {
+            Edition::Foil
+        } else if roll < 0.85 {
+            Edition::Holographic
+        } else {
+            Edition::Polychrome
+        };
+        target.cards[selected[0]].edition = Some(edition);
+        Ok(())
+    }
+}
+
+/// Sigil converts every card in hand to one random suit.
+pub struct SigilSpectral;
+
+impl SpectralEffect for SigilSpectral {
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        _selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        let suit = ALL_SUITS[rng.below(ALL_SUITS.len())];
+        for card in &mut target.cards {
+            card.suit = suit;
+        }
+        Ok(())
+    }
+}
+
+/// Ouija converts every card in hand to one random rank.
+pub struct OuijaSpectral;
+
+impl SpectralEffect for OuijaSpectral {
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        _selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        let rank = ALL_RANKS[rng.below(ALL_RANKS.len())];
+        for card in &mut target.cards {
+            card.rank = rank;
+        }
+        Ok(())
+    }
+}
+
+/// Ectoplasm makes a random joker Negative.
+pub struct EctoplasmSpectral;
+
+impl SpectralEffect for EctoplasmSpectral {
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        _selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        let index = target
+            .random_plain_joker(rng)
+            .ok_or(SpectralError::NoEligibleJoker(Spectral::Ectoplasm))?;
+        target.jokers[index].edition = Some(Edition::Negative);
+        Ok(())
+    }
+}
+
+/// Hex makes a random joker Polychrome and destroys the others, except Eternal ones.
+pub struct HexSpectral;
+
+impl SpectralEffect for HexSpectral {
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        _selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        let index = target
+            .random_plain_joker(rng)
+            .ok_or(SpectralError::NoEligibleJoker(Spectral::Hex))?;
+        target.jokers[index].edition = Some(Edition::Polychrome);
+        target.destroy_jokers_except(index);
+        Ok(())
+    }
+}
+
+/// Ankh copies a random joker and destroys the others, except Eternal ones. The copy
+/// loses Negative.
+pub struct AnkhSpectral;
+
+impl SpectralEffect for AnkhSpectral {
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        _selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        if target.jokers.is_empty() {
+            return Err(SpectralError::NoEligibleJoker(Spectral::Ankh));
+        }
+        let index = rng.below(target.jokers.len());
+        let kept = target.jokers[index];
+        let edition = kept.edition.filter(|&edition| edition != Edition::Negative);
+        target.destroy_jokers_except(index);
+        target
+            .jokers
+            .push(JokerCard::new(kept.joker, edition).with_stickers(kept.stickers));
+        Ok(())
+    }
+}
+
+/// Immolate destroys 5 random cards in hand and pays $20.
+pub struct ImmolateSpectral;
+
+impl SpectralEffect for ImmolateSpectral {
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        _selected: &[usize],
+        rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        target.destroy_random_cards(5, rng);
+        target.money += 20;
+        Ok(())
+    }
+}
+
+/// Cryptid adds 2 copies of one card to the hand.
+pub struct CryptidSpectral;
+
+impl SpectralEffect for CryptidSpectral {
+    fn selection(&self) -> (usize, usize) {
+        (1, 1)
+    }
+
+    fn apply(
+        &self,
+        target: &mut SpectralTarget,
+        selected: &[usize],
+        _rng: &mut Rng,
+    ) -> Result<(), SpectralError> {
+        let original = target.cards[selected[0]];
+        target.cards.push(original.duplicate());
+        target.cards.push(original.duplicate());
+        Ok(())
+    }
+}
+
+const FACE_RANKS: [Rank; 3] = [Rank::Jack, Rank::Queen, Rank::King];
+const NUMBERED_RANKS: [Rank; 9] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+];
+
+/// Creates a spectral card handler based on the spectral type.
+pub fn create_spectral_handler(spectral: &Spectral) -> Box<dyn SpectralEffect> {
+    match spectral {
+        Spectral::Familiar => Box::new(ReplaceCardSpectral {
+            ranks: &FACE_RANKS,
+            count: 3,
+        }),
+        Spectral::Grim => Box::new(ReplaceCardSpectral {
+            ranks: &[Rank::Ace],
+            count: 2,
+        }),
+        Spectral::Incantation => Box::new(ReplaceCardSpectral {
+            ranks: &NUMBERED_RANKS,
+            count: 4,
+        }),
+        Spectral::Talisman => Box::new(SealSpectral { seal: Seal::Gold }),
+        Spectral::DejaVu => Box::new(SealSpectral { seal: Seal::Red }),
+        Spectral::Trance => Box::new(SealSpectral { seal: Seal::Blue }),
+        Spectral::Medium => Box::new(SealSpectral { seal: Seal::Purple }),
+        Spectral::Aura => Box::new(AuraSpectral),
+        Spectral::Sigil => Box::new(SigilSpectral),
+        Spectral::Ouija => Box::new(OuijaSpectral),
+        Spectral::Ectoplasm => Box::new(EctoplasmSpectral),
+        Spectral::Hex => Box::new(HexSpectral),
+        Spectral::Ankh => Box::new(AnkhSpectral),
+        Spectral::Immolate => Box::new(ImmolateSpectral),
+        Spectral::Cryptid => Box::new(CryptidSpectral),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SpectralError {
+    WrongSelection {
+        spectral: Spectral,
+        selected: usize,
+        min: usize,
+        max: usize,
+    },
+    InvalidIndex(usize),
+    DuplicateIndex(usize),
+    NoEligibleJoker(Spectral),
+}
+
+impl fmt::Display for SpectralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpectralError::WrongSelection {
+                spectral,
+                selected,
+                min,
+                max,
+            } if min == max => write!(f, "{} needs {} cards, got {}", spectral, max, selected),
+            SpectralError::WrongSelection {
+                spectral,
+                selected,
+                min,
+                max,
+            } => write!(
+                f,
+                "{} needs {} to {} cards, got {}",
+                spectral, min, max, selected
+            ),
+            SpectralError::InvalidIndex(index) => {
+                write!(f, "card index {} is out of range", index)
+            }
+            SpectralError::DuplicateIndex(index) => {
+                write!(f, "card index {} is selected twice", index)
+            }
+            SpectralError::NoEligibleJoker(spectral) => {
+                write!(f, "{} has no joker to affect", spectral)
+            }
+        }
+    }
+}
+
+impl Error for SpectralError {}
+
+/// Uses a spectral card on the target.
+pub fn apply_spectral(
+    spectral: Spectral,
+    target: &mut SpectralTarget,
+    selected: &[usize],
+    rng: &mut Rng,
+) -> Result<(), SpectralError> {
+    let handler = create_spectral_handler(&spectral);
+
+    let (min, max) = handler.selection();
+    if selected.len() < min || selected.len() > max {
+        return Err(SpectralError::WrongSelection {
+            spectral,
+            selected: selected.len(),
+            min,
+            max,
+        });
+    }
+
+    let mut sorted = selected.to_vec();
+    sorted.sort_unstable();
+    if let Some(&invalid) = sorted.iter().find(|&&index| index >= target.cards.len()) {
+        return Err(SpectralError::InvalidIndex(invalid));
+    }
+    if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(SpectralError::DuplicateIndex(pair[0]));
+    }
+
+    handler.apply(target, &sorted, rng)
+}
+
+/// A spectral card and the cards to use it on, written as `Cryptid:2`, or just
+/// `Sigil` for spectral cards that take no selection.
+#[derive(Clone, Debug)]
+pub struct SpectralUse {
+    pub spectral: Spectral,
+    pub selected: Vec<usize>,
+}
+
+impl FromStr for SpectralUse {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (spectral, selected) = s.split_once(':').unwrap_or((s, ""));
+
+        let spectral = spectral.trim().parse()?;
+        let selected = selected
+            .split(',')
+            .filter(|index| !index.trim().is_empty())
+            .map(|index| {
+                index
+                    .trim()
+                    .parse()
+                    .map_err(|err| format!("Invalid card index `{index}`: {err}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(SpectralUse { spectral, selected })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SpectralUseError {
+    pub spectral_use: usize,
+    pub error: SpectralError,
+}
+
+impl fmt::Display for SpectralUseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "spectral {}: {}", self.spectral_use + 1, self.error)
+    }
+}
+
+impl Error for SpectralUseError {}
+
+/// Uses a sequence of spectral cards on a round in order, drawing random choices
+/// from the round's seed.
+///
+/// Indices count through `cards_played` and then `cards_held_in_hand`; new cards are
+/// added to the cards held in hand. Destroyed cards are dispatched to the jokers after
+/// each spectral card. Hand size changes from Ouija and Ectoplasm are not tracked.
+pub fn apply_spectrals(round: &mut Round, uses: &[SpectralUse]) -> Result<(), SpectralUseError> {
+    let mut rng = Rng::new(round.run.seed);
+    let mut target = SpectralTarget::from_round(round);
+
+    for (spectral_use, current) in uses.iter().enumerate() {
+        apply_spectral(current.spectral, &mut target, &current.selected, &mut rng).map_err(
+            |error| SpectralUseError {
+                spectral_use,
+                error,
+            },
+        )?;
+
+        target.write_to(round);
+        let destroyed = std::mem::take(&mut target.destroyed);
+        score::dispatch_destroyed(round, &destroyed, &mut rng);
+        target.money = round.run.money;
+    }
+
+    target.write_to(round);
+    Ok(())
+}
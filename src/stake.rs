@@ -0,0 +1,157 @@
+use std::{error::Error, fmt};
+
+use clap::ValueEnum;
+
+use crate::bignum::BigNum;
+
+/// Difficulty levels of a run; higher stakes scale blind requirements faster.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Stake {
+    White,
+    Red,
+    Green,
+    Black,
+    Blue,
+    Purple,
+    Orange,
+    Gold,
+}
+
+impl Stake {
+    /// Selects which base requirement table the stake uses.
+    fn scaling(&self) -> usize {
+        match self {
+            Stake::White | Stake::Red => 1,
+            Stake::Green | Stake::Black | Stake::Blue => 2,
+            Stake::Purple | Stake::Orange | Stake::Gold => 3,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stake::White => "White Stake",
+            Stake::Red => "Red Stake",
+            Stake::Green => "Green Stake",
+            Stake::Black => "Black Stake",
+            Stake::Blue => "Blue Stake",
+            Stake::Purple => "Purple Stake",
+            Stake::Orange => "Orange Stake",
+            Stake::Gold => "Gold Stake",
+        }
+    }
+}
+
+/// The three blinds of every ante.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum BlindKind {
+    Small,
+    Big,
+    Boss,
+}
+
+impl BlindKind {
+    /// Multiplier applied to the ante's base requirement.
+    pub fn multiplier(&self) -> BigNum {
+        match self {
+            BlindKind::Small => BigNum::one(),
+            BlindKind::Big => BigNum::new(15, 1),
+            BlindKind::Boss => BigNum::from(2),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BlindKind::Small => "Small Blind",
+            BlindKind::Big => "Big Blind",
+            BlindKind::Boss => "Boss Blind",
+        }
+    }
+}
+
+/// Base chip requirements for antes 1 to 8, one row per stake scaling level.
+const BASE_AMOUNTS: [[i64; 8]; 3] = [
+    [300, 800, 2_000, 5_000, 11_000, 20_000, 35_000, 50_000],
+    [300, 900, 2_600, 8_000, 20_000, 36_000, 60_000, 100_000],
+    [300, 1_000, 3_200, 9_000, 25_000, 60_000, 110_000, 200_000],
+];
+
+/// An ante whose requirement is too large for the endless mode formula, as in the game,
+/// which shows it as `naneinf`.
+#[derive(Debug, PartialEq)]
+pub struct AnteTooHigh {
+    pub ante: u32,
+}
+
+impl fmt::Display for AnteTooHigh {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the requirement of ante {} is too large to compute",
+            self.ante
+        )
+    }
+}
+
+impl Error for AnteTooHigh {}
+
+/// Returns the base chip requirement of an ante, before the blind multiplier.
+pub fn ante_base_amount(ante: u32, stake: Stake) -> Result<BigNum, AnteTooHigh> {
+    let amounts = &BASE_AMOUNTS[stake.scaling() - 1];
+    let amount = match ante {
+        0 => 100,
+        1..=8 => amounts[ante as usize - 1],
+        _ => {
+            // Endless mode grows super-exponentially and keeps two significant digits.
+            let c = (ante - 8) as f64;
+            let d = 1.0 + 0.2 * c;
+            let amount = (amounts[7] as f64 * (1.6 + (0.75 * c).powf(d)).powf(c)).floor();
+            return BigNum::from_f64(amount)
+                .map(|amount| amount.truncate_to_significant(2))
+                .map_err(|_| AnteTooHigh { ante });
+        }
+    };
+    Ok(BigNum::from(amount))
+}
+
+/// The chip requirement of a specific blind.
+pub struct BlindTarget {
+    pub ante: u32,
+    pub stake: Stake,
+    pub kind: BlindKind,
+    pub target: BigNum,
+}
+
+impl BlindTarget {
+    pub fn new(ante: u32, stake: Stake, kind: BlindKind) -> Result<Self, AnteTooHigh> {
+        let mut target = ante_base_amount(ante, stake)?;
+        target *= &kind.multiplier();
+        Ok(BlindTarget {
+            ante,
+            stake,
+            kind,
+            target,
+        })
+    }
+}
+
+impl fmt::Display for BlindTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Ante {} {}, {} ({})",
+            self.ante,
+            self.kind.name(),
+            self.stake.name(),
+            self.target
+        )
+    }
+}
+
+/// Describes whether a score clears a target and by what margin.
+pub fn describe_margin(score: &BigNum, target: &BigNum) -> String {
+    if score >= target {
+        format!("Clears target {} by {}", target, score - target)
+    } else {
+        format!("Falls short of target {} by {}", target, target - score)
+    }
+}
@@ -0,0 +1,295 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::consumable::Tarot;
+use crate::deck::ALL_RANKS;
+use crate::model::{Card, Enhancement, Round, Suit};
+use crate::rng::Rng;
+use crate::score;
+
+pub trait TarotEffect {
+    /// Fewest and most cards that can be selected.
+    fn selection(&self) -> (usize, usize);
+    /// Transforms the selected cards, given in ascending order.
+    fn apply(&self, cards: &mut [Card], selected: &[usize]);
+    /// Whether the selected cards are destroyed after `apply`.
+    fn destroys(&self) -> bool {
+        false
+    }
+}
+
+/// The Magician, The Empress, The Hierophant, The Lovers, The Chariot, Justice,
+/// The Devil and The Tower.
+pub struct EnhanceTarot {
+    enhancement: Enhancement,
+    max_selected: usize,
+}
+
+impl TarotEffect for EnhanceTarot {
+    fn selection(&self) -> (usize, usize) {
+        (1, self.max_selected)
+    }
+
+    fn apply(&self, cards: &mut [Card], selected: &[usize]) {
+        for &index in selected {
+            cards[index].enhancement = Some(self.enhancement);
+        }
+    }
+}
+
+/// The Star, The Moon, The Sun and The World.
+pub struct SuitTarot {
+    suit: Suit,
+}
+
+impl TarotEffect for SuitTarot {
+    fn selection(&self) -> (usize, usize) {
+        (1, 3)
+    }
+
+    fn apply(&self, cards: &mut [Card], selected: &[usize]) {
+        for &index in selected {
+            cards[index].suit = self.suit;
+        }
+    }
+}
+
+/// Strength raises the rank of up to 2 cards by 1, turning Aces into 2s.
+pub struct StrengthTarot;
+
+impl TarotEffect for StrengthTarot {
+    fn selection(&self) -> (usize, usize) {
+        (1, 2)
+    }
+
+    fn apply(&self, cards: &mut [Card], selected: &[usize]) {
+        for &index in selected {
+            let position = ALL_RANKS
+                .iter()
+                .position(|&rank| rank == cards[index].rank)
+                .unwrap_or(0);
+            cards[index].rank = ALL_RANKS[(position + 1) % ALL_RANKS.len()];
+        }
+    }
+}
+
+/// Death turns the left selected card into a copy of the right one.
+pub struct DeathTarot;
+
+impl TarotEffect for DeathTarot {
+    fn selection(&self) -> (usize, usize) {
+        (2, 2)
+    }
+
+    fn apply(&self, cards: &mut [Card], selected: &[usize]) {
+        cards[selected[0]] = cards[selected[1]].duplicate();
+    }
+}
+
+/// The Hanged Man destroys up to 2 cards.
+pub struct HangedManTarot;
+
+impl TarotEffect for HangedManTarot {
+    fn selection(&self) -> (usize, usize) {
+        (1, 2)
+    }
+
+    fn apply(&self, _cards: &mut [Card], _selected: &[usize]) {}
+
+    fn destroys(&self) -> bool {
+        true
+    }
+}
+
+/// Creates a tarot handler, or `None` for tarots that do not transform cards.
+pub fn create_tarot_handler(tarot: &Tarot) -> Option<Box<dyn TarotEffect>> {
+    let enhance = |enhancement, max_selected| -> Option<Box<dyn TarotEffect>> {
+        Some(Box::new(EnhanceTarot {
+            enhancement,
+            max_selected,
+        }))
+    };
+    let convert = |suit| -> Option<Box<dyn TarotEffect>> { Some(Box::new(SuitTarot { suit })) };
+
+    match tarot {
+        Tarot::TheMagician => enhance(Enhancement::Lucky, 2),
+        Tarot::TheEmpress => enhance(Enhancement::Mult, 2),
+        Tarot::TheHierophant => enhance(Enhancement::Bonus, 2),
+        Tarot::TheLovers => enhance(Enhancement::Wild, 1),
+        Tarot::TheChariot => enhance(Enhancement::Steel, 1),
+        Tarot::Justice => enhance(Enhancement::Glass, 1),
+        Tarot::TheDevil => enhance(Enhancement::Gold, 1),
+        Tarot::TheTower => enhance(Enhancement::Stone, 1),
+        Tarot::TheStar => convert(Suit::Diamonds),
+        Tarot::TheMoon => convert(Suit::Clubs),
+        Tarot::TheSun => convert(Suit::Hearts),
+        Tarot::TheWorld => convert(Suit::Spades),
+        Tarot::Strength => Some(Box::new(StrengthTarot)),
+        Tarot::Death => Some(Box::new(DeathTarot)),
+        Tarot::TheHangedMan => Some(Box::new(HangedManTarot)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TarotError {
+    Unsupported(Tarot),
+    WrongSelection {
+        tarot: Tarot,
+        selected: usize,
+        min: usize,
+        max: usize,
+    },
+    InvalidIndex(usize),
+    DuplicateIndex(usize),
+}
+
+impl fmt::Display for TarotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TarotError::Unsupported(tarot) => write!(f, "{} does not transform cards", tarot),
+            TarotError::WrongSelection {
+                tarot,
+                selected,
+                min,
+                max,
+            } if min == max => write!(f, "{} needs {} cards, got {}", tarot, max, selected),
+            TarotError::WrongSelection {
+                tarot,
+                selected,
+                min,
+                max,
+            } => write!(
+                f,
+                "{} needs {} to {} cards, got {}",
+                tarot, min, max, selected
+            ),
+            TarotError::InvalidIndex(index) => write!(f, "card index {} is out of range", index),
+            TarotError::DuplicateIndex(index) => {
+                write!(f, "card index {} is selected twice", index)
+            }
+        }
+    }
+}
+
+impl Error for TarotError {}
+
+/// Uses a tarot on the selected cards, removing and returning any it destroys.
+pub fn apply_tarot(
+    tarot: Tarot,
+    cards: &mut Vec<Card>,
+    selected: &[usize],
+) -> Result<Vec<Card>, TarotError> {
+    let handler = create_tarot_handler(&tarot).ok_or(TarotError::Unsupported(tarot))?;
+
+    let (min, max) = handler.selection();
+    if selected.len() < min || selected.len() > max {
+        return Err(TarotError::WrongSelection {
+            tarot,
+            selected: selected.len(),
+            min,
+            max,
+        });
+    }
+
+    let mut sorted = selected.to_vec();
+    sorted.sort_unstable();
+    if let Some(&invalid) = sorted.iter().find(|&&index| index >= cards.len()) {
+        return Err(TarotError::InvalidIndex(invalid));
+    }
+    if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(TarotError::DuplicateIndex(pair[0]));
+    }
+
+    handler.apply(cards, &sorted);
+    let mut destroyed = Vec::new();
+    if handler.destroys() {
+        for &index in sorted.iter().rev() {
+            destroyed.push(cards.remove(index));
+        }
+        destroyed.reverse();
+    }
+    Ok(destroyed)
+}
+
+/// Uses a tarot on cards of a round.
+///
+/// Indices count through `cards_played` and then `cards_held_in_hand`. The round's
+/// `deck` is left untouched. Destroyed cards are dispatched to the jokers, which roll
+/// any chances with `rng`.
+pub fn apply_tarot_to_round(
+    round: &mut Round,
+    tarot: Tarot,
+    selected: &[usize],
+    rng: &mut Rng,
+) -> Result<(), TarotError> {
+    let played = round.cards_played.len();
+    let mut cards = round.cards_played.clone();
+    cards.extend(round.cards_held_in_hand.iter().copied());
+
+    let destroyed = apply_tarot(tarot, &mut cards, selected)?;
+
+    let destroyed_played = if destroyed.is_empty() {
+        0
+    } else {
+        selected.iter().filter(|&&index| index < played).count()
+    };
+    round.cards_held_in_hand = cards.split_off(played - destroyed_played);
+    round.cards_played = cards;
+    score::dispatch_destroyed(round, &destroyed, rng);
+    Ok(())
+}
+
+/// A tarot and the cards to use it on, written as `The Empress:0,1`.
+#[derive(Clone, Debug)]
+pub struct TarotUse {
+    pub tarot: Tarot,
+    pub selected: Vec<usize>,
+}
+
+impl FromStr for TarotUse {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (tarot, selected) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Expected `TAROT:INDICES`, got `{s}`"))?;
+
+        let tarot = tarot.trim().parse()?;
+        let selected = selected
+            .split(',')
+            .map(|index| {
+                index
+                    .trim()
+                    .parse()
+                    .map_err(|err| format!("Invalid card index `{index}`: {err}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(TarotUse { tarot, selected })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TarotUseError {
+    pub tarot_use: usize,
+    pub error: TarotError,
+}
+
+impl fmt::Display for TarotUseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tarot {}: {}", self.tarot_use + 1, self.error)
+    }
+}
+
+impl Error for TarotUseError {}
+
+/// Uses a sequence of tarots on a round in order, rolling jokers' chances from the
+/// round's seed.
+pub fn apply_tarots(round: &mut Round, uses: &[TarotUse]) -> Result<(), TarotUseError> {
+    let mut rng = Rng::new(round.run.seed);
+    for (tarot_use, current) in uses.iter().enumerate() {
+        apply_tarot_to_round(round, current.tarot, &current.selected, &mut rng)
+            .map_err(|error| TarotUseError { tarot_use, error })?;
+    }
+    Ok(())
+}
@@ -0,0 +1,341 @@
+use std::fmt;
+
+use crate::deck::{self, DEFAULT_HAND_SIZE};
+use crate::input::{self, InputFormat};
+use crate::model::{Card, Round};
+
+/// Most cards a single hand can play.
+const MAX_CARDS_PLAYED: usize = 5;
+
+/// A one-based position in the input, ordered by line and then column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The round cannot be scored as written.
+    Error,
+    /// The round can be scored but is unlikely to come up in a run.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// One problem found in a round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = self.location {
+            write!(f, "{}: ", location)?;
+        }
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
+/// Every problem found in a round, in the order they appear in the input. Problems
+/// without a location come last.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ValidationReport {
+    pub fn has_errors(&self) -> bool {
+        self.count(Severity::Error) > 0
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    }
+
+    fn push(&mut self, severity: Severity, location: Option<Location>, message: String) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            location,
+            message,
+        });
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diagnostic in &self.diagnostics {
+            writeln!(f, "{}", diagnostic)?;
+        }
+        write!(
+            f,
+            "{} error(s), {} warning(s)",
+            self.count(Severity::Error),
+            self.count(Severity::Warning)
+        )
+    }
+}
+
+/// Parses a round and reports everything that makes it impossible or unusual.
+pub fn validate_round(source: &str, format: InputFormat) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let round: Round = match input::parse(source, format) {
+        Ok(round) => round,
+        Err(error) => {
+            let message = error.message();
+            let location = error.location(source).map(|location| {
+                Locator::new(source)
+                    .quoted_value(&message, location.line)
+                    .unwrap_or(location)
+            });
+            report.push(Severity::Error, location, message);
+            return report;
+        }
+    };
+    let locator = Locator::new(source);
+
+    if round.cards_played.is_empty() {
+        report.push(
+            Severity::Error,
+            locator.key("cards_played"),
+            "no cards played".to_string(),
+        );
+    } else if round.cards_played.len() > MAX_CARDS_PLAYED {
+        report.push(
+            Severity::Error,
+            locator.item("cards_played", MAX_CARDS_PLAYED),
+            format!(
+                "{} cards played, but a hand plays at most {}",
+                round.cards_played.len(),
+                MAX_CARDS_PLAYED
+            ),
+        );
+    }
+
+    let hand_size = DEFAULT_HAND_SIZE
+        + round
+            .run
+            .deck_variant
+            .map_or(0, |variant| variant.hand_size_bonus());
+    let cards_in_hand = round.cards_played.len() + round.cards_held_in_hand.len();
+    if cards_in_hand > hand_size {
+        report.push(
+            Severity::Warning,
+            locator.key("cards_held_in_hand"),
+            format!(
+                "{} cards in hand is more than the hand size of {}",
+                cards_in_hand, hand_size
+            ),
+        );
+    }
+
+    if let Err(error) = round.check_joker_slots() {
+        report.push(
+            Severity::Error,
+            locator
+                .item("jokers", error.slots)
+                .or_else(|| locator.key("jokers")),
+            error.to_string(),
+        );
+    }
+
+    check_copies(&round, &locator, &mut report);
+    report
+        .diagnostics
+        .sort_by_key(|diagnostic| (diagnostic.location.is_none(), diagnostic.location));
+    report
+}
+
+/// Reports cards in play more often than the deck holds them.
+fn check_copies(round: &Round, locator: &Locator, report: &mut ValidationReport) {
+    let (deck, severity) = match &round.run.deck {
+        Some(deck) => (deck.clone(), Severity::Error),
+        // Cards can be added to the starting deck during a run.
+        None => (
+            deck::starting_cards(round.run.deck_variant, round.run.seed),
+            Severity::Warning,
+        ),
+    };
+    let copies_in = |cards: &[Card], card: &Card| {
+        cards
+            .iter()
+            .filter(|other| other.rank == card.rank && other.suit == card.suit)
+            .count()
+    };
+
+    let in_play: Vec<(&str, usize, &Card)> = round
+        .cards_played
+        .iter()
+        .enumerate()
+        .map(|(index, card)| ("cards_played", index, card))
+        .chain(
+            round
+                .cards_held_in_hand
+                .iter()
+                .enumerate()
+                .map(|(index, card)| ("cards_held_in_hand", index, card)),
+        )
+        .collect();
+
+    let mut seen: Vec<Card> = Vec::new();
+    for (key, index, card) in in_play {
+        let available = copies_in(&deck, card);
+        let used = copies_in(&seen, card) + 1;
+        seen.push(*card);
+        if used == available + 1 {
+            let name = Card::new(card.rank, card.suit, None, None);
+            report.push(
+                severity,
+                locator.item(key, index),
+                format!(
+                    "more copies of {} in play than the {} in the deck",
+                    name, available
+                ),
+            );
+        }
+    }
+}
+
+/// Finds top-level keys and their sequence items in YAML, JSON or TOML source.
+///
+/// Handles block sequences (`- item` lines) and bracketed sequences spread over any
+/// number of lines, which covers every round the CLI writes.
+struct Locator<'s> {
+    lines: Vec<&'s str>,
+}
+
+impl<'s> Locator<'s> {
+    fn new(source: &'s str) -> Self {
+        Locator {
+            lines: source.lines().collect(),
+        }
+    }
+
+    /// The line a key is on and the byte offset just past its `:` or `=`.
+    fn find_key(&self, key: &str) -> Option<(usize, usize)> {
+        self.lines.iter().enumerate().find_map(|(line, text)| {
+            let trimmed = text.trim_start();
+            let rest = trimmed.strip_prefix('"').unwrap_or(trimmed);
+            let rest = rest.strip_prefix(key)?;
+            let rest = rest.strip_prefix('"').unwrap_or(rest);
+            let value = rest.trim_start().strip_prefix([':', '='])?;
+            Some((line, text.len() - value.len()))
+        })
+    }
+
+    fn key(&self, key: &str) -> Option<Location> {
+        self.find_key(key).map(|(line, _)| {
+            let text = self.lines[line];
+            self.at(line, text.len() - text.trim_start().len())
+        })
+    }
+
+    /// Location of a byte offset within a line, counting columns in characters.
+    fn at(&self, line: usize, offset: usize) -> Location {
+        Location {
+            line: line + 1,
+            column: self.lines[line][..offset].chars().count() + 1,
+        }
+    }
+
+    /// Location of the first value quoted in backticks in `message`, searching from the
+    /// start of `line`. Parsers report an invalid item at the start of its sequence or
+    /// just past it, so this points at the item itself.
+    fn quoted_value(&self, message: &str, line: usize) -> Option<Location> {
+        let (_, rest) = message.split_once('`')?;
+        let (value, _) = rest.split_once('`')?;
+        if value.is_empty() {
+            return None;
+        }
+        self.lines
+            .iter()
+            .enumerate()
+            .skip(line.saturating_sub(1))
+            .find_map(|(line, text)| text.find(value).map(|offset| self.at(line, offset)))
+    }
+
+    /// Location of the `index`-th item of the sequence under a key.
+    fn item(&self, key: &str, index: usize) -> Option<Location> {
+        let (line, value) = self.find_key(key)?;
+        let rest = &self.lines[line][value..];
+        if rest.trim_start().starts_with('[') {
+            let open = value + rest.find('[')? + 1;
+            return self.bracketed_item(line, open, index);
+        }
+
+        let items = self.lines[line + 1..]
+            .iter()
+            .enumerate()
+            .take_while(|(_, text)| text.starts_with([' ', '-', '#']) || text.trim().is_empty())
+            .filter_map(|(offset, text)| {
+                let indent = text.len() - text.trim_start().len();
+                let item = text.trim_start().strip_prefix('-')?;
+                let value = indent + 1 + (item.len() - item.trim_start().len());
+                Some((line + 1 + offset, indent, value))
+            });
+        let (_, indent, _) = items.clone().next()?;
+        items
+            .filter(|(_, item_indent, _)| *item_indent == indent)
+            .nth(index)
+            .map(|(line, _, value)| self.at(line, value))
+    }
+
+    /// Location of the `index`-th comma separated item after the `[` ending at `open`.
+    fn bracketed_item(&self, line: usize, open: usize, index: usize) -> Option<Location> {
+        let mut items = 0;
+        let mut expecting_item = true;
+        let mut in_quotes = false;
+        for (line_index, text) in self.lines.iter().enumerate().skip(line) {
+            let start = if line_index == line { open } else { 0 };
+            let mut escaped = false;
+            for (offset, character) in text[start..].char_indices() {
+                if in_quotes {
+                    match character {
+                        _ if escaped => escaped = false,
+                        '\\' => escaped = true,
+                        '"' => in_quotes = false,
+                        _ => {}
+                    }
+                    continue;
+                }
+                match character {
+                    ']' => return None,
+                    ',' => expecting_item = true,
+                    '#' => break,
+                    _ if character.is_whitespace() => {}
+                    _ => {
+                        in_quotes = character == '"';
+                        if expecting_item {
+                            if items == index {
+                                return Some(self.at(line_index, start + offset));
+                            }
+                            items += 1;
+                            expecting_item = false;
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,98 @@
+use crate::model::{named, string_serde};
+
+/// Permanent upgrades bought from the shop.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Voucher {
+    /// +1 card slot in the shop.
+    Overstock,
+    /// +1 more card slot in the shop.
+    OverstockPlus,
+    /// Everything in the shop is 25% off.
+    ClearanceSale,
+    /// Everything in the shop is 50% off.
+    Liquidation,
+    /// Foil, Holographic and Polychrome jokers appear 2X more often.
+    Hone,
+    /// Foil, Holographic and Polychrome jokers appear 4X more often.
+    GlowUp,
+    /// Rerolls cost $2 less.
+    RerollSurplus,
+    /// Rerolls cost $2 less again.
+    RerollGlut,
+    /// +1 consumable slot.
+    CrystalBall,
+    /// Tarot cards appear 2X more often in the shop.
+    TarotMerchant,
+    /// Tarot cards appear 4X more often in the shop.
+    TarotTycoon,
+    /// Planet cards appear 2X more often in the shop.
+    PlanetMerchant,
+    /// Planet cards appear 4X more often in the shop.
+    PlanetTycoon,
+    /// Does nothing.
+    Blank,
+    /// +1 joker slot.
+    Antimatter,
+}
+
+impl Voucher {
+    pub const ALL: &'static [Voucher] = &[
+        Voucher::Overstock,
+        Voucher::OverstockPlus,
+        Voucher::ClearanceSale,
+        Voucher::Liquidation,
+        Voucher::Hone,
+        Voucher::GlowUp,
+        Voucher::RerollSurplus,
+        Voucher::RerollGlut,
+        Voucher::CrystalBall,
+        Voucher::TarotMerchant,
+        Voucher::TarotTycoon,
+        Voucher::PlanetMerchant,
+        Voucher::PlanetTycoon,
+        Voucher::Blank,
+        Voucher::Antimatter,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Voucher::Overstock => "Overstock",
+            Voucher::OverstockPlus => "Overstock Plus",
+            Voucher::ClearanceSale => "Clearance Sale",
+            Voucher::Liquidation => "Liquidation",
+            Voucher::Hone => "Hone",
+            Voucher::GlowUp => "Glow Up",
+            Voucher::RerollSurplus => "Reroll Surplus",
+            Voucher::RerollGlut => "Reroll Glut",
+            Voucher::CrystalBall => "Crystal Ball",
+            Voucher::TarotMerchant => "Tarot Merchant",
+            Voucher::TarotTycoon => "Tarot Tycoon",
+            Voucher::PlanetMerchant => "Planet Merchant",
+            Voucher::PlanetTycoon => "Planet Tycoon",
+            Voucher::Blank => "Blank",
+            Voucher::Antimatter => "Antimatter",
+        }
+    }
+
+    /// The voucher that must be redeemed before this one can appear.
+    pub fn requires(&self) -> Option<Voucher> {
+        match self {
+            Voucher::OverstockPlus => Some(Voucher::Overstock),
+            Voucher::Liquidation => Some(Voucher::ClearanceSale),
+            Voucher::GlowUp => Some(Voucher::Hone),
+            Voucher::RerollGlut => Some(Voucher::RerollSurplus),
+            Voucher::TarotTycoon => Some(Voucher::TarotMerchant),
+            Voucher::PlanetTycoon => Some(Voucher::PlanetMerchant),
+            Voucher::Antimatter => Some(Voucher::Blank),
+            _ => None,
+        }
+    }
+
+    /// Shop price before discounts.
+    pub fn base_cost(&self) -> i64 {
+        10
+    }
+}
+
+named!(Voucher);
+string_serde!(Voucher);
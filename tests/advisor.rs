@@ -0,0 +1,42 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn discarding_uses_up_a_discard_before_the_play() {
+    let situation = input(
+        "advisor_mystic_summit.yml",
+        "hand: [K♥, K♠, 2♣]\ndeck: [3♦]\njokers: [Mystic Summit]\n\
+         discards_left: 1\nhands_left: 2\n",
+    );
+    let output = ortalab(&["advise", &situation, "--samples", "1"]);
+    assert!(
+        output
+            .success()
+            .starts_with("Recommended: Discard 2♣ (Expected Score: 510.0)\n  Pair: 100.0%\n"),
+        "{}",
+        output.stdout
+    );
+    assert!(
+        output.stdout.contains("Discard K♥ (Expected Score: 240.0)"),
+        "{}",
+        output.stdout
+    );
+}
+
+#[test]
+fn last_hand_is_played_with_no_hands_left() {
+    let situation = input(
+        "advisor_acrobat.yml",
+        "hand: [K♥, K♠, 2♣]\ndeck: [3♦]\njokers: [Acrobat]\n\
+         discards_left: 1\nhands_left: 1\n",
+    );
+    let output = ortalab(&["advise", &situation, "--samples", "1"]);
+    assert!(
+        output
+            .success()
+            .starts_with("Recommended: Keep hand (Expected Score: 180.0)\n"),
+        "{}",
+        output.stdout
+    );
+}
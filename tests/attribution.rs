@@ -0,0 +1,32 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn attribution_totals_each_source_and_scores_without_it() {
+    let round = input(
+        "attribution_totals.yml",
+        "cards_played: [K♥, K♠]\ncards_held_in_hand: [Q♣ Steel]\n\
+         jokers: [Joker Foil, Baron Polychrome]\n",
+    );
+    let output = ortalab(&[&round, "--attribution"]);
+    assert!(
+        output.success().ends_with(
+            "Totals by source:\n  \
+             Pair: +10 Chips, +2 Mult\n  \
+             K♥: +10 Chips\n  \
+             K♠: +10 Chips\n  \
+             Q♣ Steel: x1.5 Mult\n  \
+             Joker Foil: +50 Chips, +4 Mult\n  \
+             Baron Polychrome: x1.5 Mult\n\
+             Score without each joker and card:\n  \
+             Joker Foil: 135 (+705)\n  \
+             Baron Polychrome: 560 (+280)\n  \
+             K♥ (played): 536 (+304)\n  \
+             K♠ (played): 536 (+304)\n  \
+             Q♣ Steel (held): 720 (+120)\n"
+        ),
+        "{}",
+        output.stdout
+    );
+}
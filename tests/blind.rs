@@ -0,0 +1,45 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn blind_stops_once_the_target_is_reached() {
+    let blind = input(
+        "blind_stop.yml",
+        "target: 50\ndeck: [K♥, K♠, K♦, K♣]\nhand_size: 2\n\
+         actions:\n  - play: [0, 1]\n  - play: [0, 1]\n",
+    );
+    assert_eq!(
+        ortalab(&["blind", &blind]).success(),
+        "Hand 1: Pair [K♦, K♠] +60 (Total: 60 / 50)\n\
+         Blind beaten with 60 after 1 hand(s) and 0 discard(s)\n"
+    );
+}
+
+/// Business Card pays $2 on a 1 in 2 chance for each scored face card.
+fn business_card_blind(seed: u64) -> String {
+    let blind = input(
+        &format!("blind_business_card_{seed}.yml"),
+        &format!(
+            "target: 10000\nseed: {seed}\ndeck: [K♥, K♠, K♦, K♣, Q♥, Q♠, Q♦, Q♣]\n\
+             hand_size: 4\njokers: [Business Card]\n\
+             actions:\n  - play: [0, 1, 2, 3]\n  - play: [0, 1, 2, 3]\n"
+        ),
+    );
+    ortalab(&["blind", &blind]).success().to_string()
+}
+
+#[test]
+fn seed_drives_the_chances_of_every_hand() {
+    assert!(
+        business_card_blind(0)
+            .ends_with("Money: $0 -> $7 (+$6 during scoring, +$1 at end of round)\n")
+    );
+    assert_eq!(
+        business_card_blind(1),
+        "Hand 1: Two Pair [Q♥, K♣, K♦, Q♣] +120 (Total: 120 / 10000)\n\
+         Hand 2: Two Pair [Q♠, Q♦, K♥, K♠] +120 (Total: 240 / 10000)\n\
+         Blind failed with 240 after 2 hand(s) and 0 discard(s)\n\
+         Money: $0 -> $4 (+$4 during scoring, +$0 at end of round)\n"
+    );
+}
@@ -0,0 +1,58 @@
+//! Runs the `ortalab` binary for the integration tests.
+
+#![allow(dead_code)]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// What one run of the binary printed and how it exited.
+pub struct Run {
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Run {
+    /// Standard output, panicking with standard error if the run failed.
+    pub fn success(&self) -> &str {
+        assert_eq!(self.code, Some(0), "ortalab failed: {}", self.stderr);
+        &self.stdout
+    }
+}
+
+/// Runs the binary with the given arguments.
+pub fn ortalab(args: &[&str]) -> Run {
+    let output = Command::new(env!("CARGO_BIN_EXE_ortalab"))
+        .args(args)
+        .output()
+        .expect("ortalab runs");
+    Run {
+        code: output.status.code(),
+        stdout: String::from_utf8(output.stdout).expect("stdout is UTF-8"),
+        stderr: String::from_utf8(output.stderr).expect("stderr is UTF-8"),
+    }
+}
+
+/// Writes an input file into the test scratch directory and returns its path. Names must
+/// be unique across tests, since the tests run in parallel.
+pub fn input(name: &str, contents: &str) -> String {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, contents).expect("input is written");
+    path_string(path)
+}
+
+/// Path of a file under `fixtures/`.
+pub fn fixture(path: &str) -> String {
+    path_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join(path),
+    )
+}
+
+fn path_string(path: PathBuf) -> String {
+    path.into_os_string().into_string().expect("path is UTF-8")
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn hand_refills_to_hand_size_after_each_play() {
+    let blind = input(
+        "deck_refill.yml",
+        "target: 10\ndeck: [K♥, K♠, 2♣]\nhand_size: 2\nactions:\n  - play: [0]\n  - play: [0, 1]\n",
+    );
+    assert_eq!(
+        ortalab(&["blind", &blind]).success(),
+        "Hand 1: High Card [2♣] +7 (Total: 7 / 10)\n\
+         Hand 2: Pair [K♥, K♠] +60 (Total: 67 / 10)\n\
+         Blind beaten with 67 after 2 hand(s) and 0 discard(s)\n"
+    );
+}
+
+#[test]
+fn selections_must_be_in_hand() {
+    let blind = input(
+        "deck_out_of_hand.yml",
+        "target: 10\ndeck: [K♥, K♠, 2♣]\nhand_size: 2\nactions:\n  - play: [2]\n",
+    );
+    let output = ortalab(&["blind", &blind]);
+    assert_eq!(output.code, Some(1));
+    assert_eq!(
+        output.stderr,
+        "Error: action 1: card index 2 is not in the hand\n"
+    );
+}
+
+#[test]
+fn discards_run_out() {
+    let blind = input(
+        "deck_discards.yml",
+        "target: 1000\ndeck: [K♥, K♠, 2♣, 3♦, 4♠, 5♥]\nhand_size: 3\ndiscards: 1\n\
+         actions:\n  - discard: [0]\n  - discard: [0]\n",
+    );
+    let output = ortalab(&["blind", &blind]);
+    assert_eq!(output.code, Some(1));
+    assert_eq!(output.stderr, "Error: action 2: no discards left\n");
+}
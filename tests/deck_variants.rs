@@ -0,0 +1,58 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn plasma_deck_explains_the_balance() {
+    let round = input(
+        "variant_plasma.yml",
+        "cards_played: [K♥, K♠]\ndeck_variant: Plasma Deck\n",
+    );
+    assert_eq!(
+        ortalab(&[&round, "--explain"]).success(),
+        "Pair (Plasma Deck balanced 30 Chips x 2 Mult to 16 x 16, Final Score: 256)\n"
+    );
+}
+
+#[test]
+fn abandoned_deck_has_no_face_cards_to_draw() {
+    // 40 cards minus the two played leave Blue Joker 38: +76 Chips.
+    let round = input(
+        "variant_abandoned.yml",
+        "cards_played: [K♥, K♠]\njokers: [Blue Joker]\ndeck_variant: Abandoned Deck\n",
+    );
+    assert_eq!(ortalab(&[&round]).success(), "212\n");
+}
+
+#[test]
+fn checkered_deck_holds_only_hearts_and_spades() {
+    let blind = input(
+        "variant_checkered.yml",
+        "target: 100000\ndeck_variant: Checkered Deck\n\
+         actions:\n  - play: [0, 1, 2, 3, 4]\n  - play: [0, 1, 2, 3, 4]\n",
+    );
+    let output = ortalab(&["blind", &blind]);
+    assert_eq!(
+        output.success(),
+        "Hand 1: Pair [9♥, Q♠, 10♠, 3♥, 3♥] +32 (Total: 32 / 100000)\n\
+         Hand 2: High Card [K♥, 7♥, J♠, 4♥, 2♠] +15 (Total: 47 / 100000)\n\
+         Blind failed with 47 after 2 hand(s) and 0 discard(s)\n"
+    );
+}
+
+#[test]
+fn painted_deck_draws_two_more_cards() {
+    let blind = |name: &str, index: usize| {
+        input(
+            name,
+            &format!("target: 100000\ndeck_variant: Painted Deck\nactions:\n  - play: [{index}]\n"),
+        )
+    };
+    ortalab(&["blind", &blind("variant_painted_9.yml", 9)]).success();
+
+    let output = ortalab(&["blind", &blind("variant_painted_10.yml", 10)]);
+    assert_eq!(
+        output.stderr,
+        "Error: action 1: card index 10 is not in the hand\n"
+    );
+}
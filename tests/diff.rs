@@ -0,0 +1,40 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn diff_lists_changes_and_diverging_steps() {
+    let before = input(
+        "diff_before.yml",
+        "cards_played: [K♥, K♠]\njokers: [Joker]\n",
+    );
+    let after = input(
+        "diff_after.yml",
+        "cards_played: [K♥, K♠]\ncards_held_in_hand: [K♣]\njokers: [Joker Foil, Baron]\n",
+    );
+    assert_eq!(
+        ortalab(&["diff", &before, &after]).success(),
+        "Hand: Pair\n\
+         Held cards: + K♣\n\
+         Jokers: - Joker\n\
+         Jokers: + Joker Foil\n\
+         Jokers: + Baron\n\
+         Diverging steps:\n  \
+         - Joker: +4 Mult (Chips +0, Mult +4)\n  \
+         + Baron: x1.5 Mult (Chips +0, Mult +1)\n  \
+         + Joker Foil: +50 Chips (Chips +50, Mult +0)\n  \
+         + Joker Foil: +4 Mult (Chips +0, Mult +4)\n\
+         Chips: 30 -> 80 (+50)\n\
+         Mult: 6 -> 7 (+1)\n\
+         Score: 180 -> 560 (+380)\n"
+    );
+}
+
+#[test]
+fn diff_of_a_round_with_itself_changes_nothing() {
+    let round = input("diff_same.yml", "cards_played: [K♥, K♠]\njokers: [Joker]\n");
+    assert_eq!(
+        ortalab(&["diff", &round, &round]).success(),
+        "Hand: Pair\nChips: 30 -> 30 (+0)\nMult: 6 -> 6 (+0)\nScore: 180 -> 180 (+0)\n"
+    );
+}
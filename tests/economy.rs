@@ -0,0 +1,24 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn money_line_splits_scoring_and_end_of_round_earnings() {
+    // Gold Seal pays $3 when scored; the held Gold card $3, Golden Joker $4 and interest
+    // on $15 another $3 at the end of the round.
+    let round = input(
+        "economy_gold.yml",
+        "cards_played: [K♥ Gold Seal, K♠]\ncards_held_in_hand: [2♣ Gold]\n\
+         jokers: [Golden Joker]\nmoney: 12\n",
+    );
+    assert_eq!(
+        ortalab(&[&round]).success(),
+        "60\nMoney: $12 -> $25 (+$3 during scoring, +$10 at end of round)\n"
+    );
+}
+
+#[test]
+fn money_line_is_left_out_when_nothing_changes() {
+    let round = input("economy_none.yml", "cards_played: [K♥, K♠]\n");
+    assert_eq!(ortalab(&[&round]).success(), "60\n");
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn impossible_round_exits_with_1() {
+    let round = input("exit_no_cards.yml", "cards_played: []\n");
+    let output = ortalab(&[&round]);
+    assert_eq!(output.code, Some(1));
+    assert_eq!(output.stderr, "Error: no cards played\n");
+}
+
+#[test]
+fn usage_error_exits_with_2() {
+    assert_eq!(ortalab(&["--bogus"]).code, Some(2));
+}
+
+#[test]
+fn unreadable_file_exits_with_3() {
+    let missing = format!("{}/exit_missing.yml", env!("CARGO_TARGET_TMPDIR"));
+    assert_eq!(ortalab(&[&missing]).code, Some(3));
+}
+
+#[test]
+fn malformed_file_exits_with_4() {
+    let round = input("exit_malformed.yml", "cards_played: [K♥\n");
+    let output = ortalab(&[&round]);
+    assert_eq!(output.code, Some(4));
+    assert!(
+        output.stderr.contains("line 2 column 1"),
+        "{}",
+        output.stderr
+    );
+}
+
+#[test]
+fn ante_past_the_endless_formula_exits_with_1() {
+    let round = input("exit_ante_40.yml", "cards_played: [K♥, K♠]\n");
+    let output = ortalab(&[&round, "--ante", "40"]);
+    assert_eq!(output.code, Some(1));
+    assert_eq!(
+        output.stderr,
+        "Error: the requirement of ante 40 is too large to compute\n"
+    );
+}
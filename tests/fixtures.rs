@@ -0,0 +1,44 @@
+mod common;
+
+use std::{fs, path::Path};
+
+/// Collects every round under `dir`, in a stable order.
+fn rounds(dir: &Path, found: &mut Vec<std::path::PathBuf>) {
+    for entry in fs::read_dir(dir).expect("fixture directory is readable") {
+        let path = entry.expect("fixture entry is readable").path();
+        if path.is_dir() {
+            rounds(&path, found);
+        } else if path.extension().is_some_and(|extension| extension == "yml") {
+            found.push(path);
+        }
+    }
+    found.sort();
+}
+
+#[test]
+fn fixtures_score_their_expected_values() {
+    let mut found = Vec::new();
+    rounds(Path::new(&common::fixture("")), &mut found);
+    assert!(!found.is_empty());
+
+    let mut failures = Vec::new();
+    for path in &found {
+        let text = fs::read_to_string(path).expect("fixture is readable");
+        let expected = text
+            .lines()
+            .find_map(|line| line.strip_prefix("# Expected score: "))
+            .unwrap_or_else(|| panic!("{} has no expected score", path.display()));
+
+        let run = common::ortalab(&[path.to_str().expect("path is UTF-8")]);
+        let actual = run.success().lines().next().unwrap_or_default();
+        if actual != expected {
+            failures.push(format!(
+                "{}: expected {}, got {}",
+                path.display(),
+                expected,
+                actual
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
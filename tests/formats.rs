@@ -0,0 +1,66 @@
+mod common;
+
+use common::{input, ortalab};
+
+/// One round, written in each input format.
+const YAML: &str = "cards_played: [K♥ Mult, K♠ Foil, 5♦]\n\
+                    cards_held_in_hand: [Q♣ Steel]\n\
+                    jokers: [Joker, Greedy Joker Holographic]\n\
+                    money: 4\n\
+                    hand_play_counts: {Pair: 3}\n\
+                    deck_variant: Plasma Deck\n";
+const JSON: &str = "{\n  \"cards_played\": [\"K♥ Mult\", \"K♠ Foil\", \"5♦\"],\n  \
+                    \"cards_held_in_hand\": [\"Q♣ Steel\"],\n  \
+                    \"jokers\": [\"Joker\", \"Greedy Joker Holographic\"],\n  \
+                    \"money\": 4,\n  \
+                    \"hand_play_counts\": {\"Pair\": 3},\n  \
+                    \"deck_variant\": \"Plasma Deck\"\n}\n";
+const TOML: &str = "cards_played = [\"K♥ Mult\", \"K♠ Foil\", \"5♦\"]\n\
+                    cards_held_in_hand = [\"Q♣ Steel\"]\n\
+                    jokers = [\"Joker\", \"Greedy Joker Holographic\"]\n\
+                    money = 4\n\
+                    deck_variant = \"Plasma Deck\"\n\n\
+                    [hand_play_counts]\n\
+                    Pair = 3\n";
+
+const SCORED: &str = "2652\nMoney: $4 -> $4 (+$0 during scoring, +$0 at end of round)\n";
+
+#[test]
+fn formats_score_the_same_round() {
+    for (name, contents) in [
+        ("formats_round.yml", YAML),
+        ("formats_round.json", JSON),
+        ("formats_round.toml", TOML),
+    ] {
+        let round = input(name, contents);
+        assert_eq!(ortalab(&[&round]).success(), SCORED, "{name}");
+        assert_eq!(
+            ortalab(&["--explain", &round]).success(),
+            "Pair (Plasma Deck balanced 80 Chips x 23 Mult to 51.5 x 51.5, \
+             Final Score: 2652)\n\
+             Money: $4 -> $4 (+$0 during scoring, +$0 at end of round)\n",
+            "{name}"
+        );
+        assert_eq!(
+            ortalab(&["validate", &round]).success(),
+            "0 error(s), 0 warning(s)\n",
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn input_format_overrides_the_extension() {
+    let round = input("formats_override.txt", TOML);
+
+    // Without the flag, an unknown extension is read as YAML.
+    assert_eq!(ortalab(&[&round]).code, Some(4));
+    assert_eq!(
+        ortalab(&["--input-format", "toml", &round]).success(),
+        SCORED
+    );
+    assert_eq!(
+        ortalab(&["validate", "--input-format", "toml", &round]).success(),
+        "0 error(s), 0 warning(s)\n"
+    );
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn negative_jokers_take_no_joker_slot() {
+    let round = input(
+        "negative_extra_slot.yml",
+        "cards_played: [K♥, K♠]\njokers: [Joker, Joker, Joker, Joker, Joker, Joker Negative]\n",
+    );
+    // Six Jokers add 24 Mult: 30 x 26.
+    assert_eq!(ortalab(&[&round]).success(), "780\n");
+}
+
+#[test]
+fn too_many_jokers_are_rejected() {
+    let round = input(
+        "negative_too_many.yml",
+        "cards_played: [K♥, K♠]\njokers: [Joker, Joker, Joker, Joker, Joker, Joker]\n",
+    );
+    let output = ortalab(&[&round]);
+    assert_eq!(output.code, Some(1));
+    assert_eq!(
+        output.stderr,
+        "Error: 6 non-Negative jokers do not fit in 5 joker slots\n"
+    );
+}
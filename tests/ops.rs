@@ -0,0 +1,40 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn every_operation_is_traced_with_the_running_totals() {
+    let round = input(
+        "ops_trace.yml",
+        "cards_played: [K♥, K♠]\ncards_held_in_hand: [Q♣ Steel]\n\
+         jokers: [Joker Foil, Baron Polychrome]\n",
+    );
+    let output = ortalab(&[&round, "--attribution"]);
+    assert!(
+        output.success().starts_with(
+            "Score: 840 (Pair)\n\
+             Contributions in order:\n  \
+             Pair: +10 Chips (10 x 0)\n  \
+             Pair: +2 Mult (10 x 2)\n  \
+             K♥: +10 Chips (20 x 2)\n  \
+             K♠: +10 Chips (30 x 2)\n  \
+             Q♣ Steel: x1.5 Mult (30 x 3)\n  \
+             Joker Foil: +50 Chips (80 x 3)\n  \
+             Joker Foil: +4 Mult (80 x 7)\n  \
+             Baron Polychrome: x1.5 Mult (80 x 10.5)\n"
+        ),
+        "{}",
+        output.stdout
+    );
+}
+
+#[test]
+fn money_operations_do_not_touch_the_score() {
+    let round = input("ops_money.yml", "cards_played: [K♥ Gold Seal, K♠]\n");
+    let output = ortalab(&[&round, "--attribution"]);
+    assert!(
+        output.success().contains("  K♥ Gold Seal: +$3 (10 x 2)\n"),
+        "{}",
+        output.stdout
+    );
+}
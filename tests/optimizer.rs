@@ -0,0 +1,17 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn optimizer_moves_multipliers_after_additions() {
+    let round = input(
+        "optimizer_polychrome.yml",
+        "cards_played: [K♥, K♠]\njokers: [Baron Polychrome, Joker]\n",
+    );
+    assert_eq!(
+        ortalab(&[&round, "--optimize-jokers"]).success(),
+        "Current order: Baron Polychrome, Joker (Score: 210)\n\
+         Best order: Joker, Baron Polychrome (Score: 270)\n\
+         Orders tried: 2 (exhaustive)\n"
+    );
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::{input, ortalab};
+
+/// Scores a Pair of Kings (30 x 2) with one joker and the given play counts.
+fn score_with(name: &str, joker: &str, counts: &str) -> String {
+    let round = input(
+        &format!("play_counts_{name}.yml"),
+        &format!("cards_played: [K♥, K♠]\njokers: [{joker}]\n{counts}"),
+    );
+    ortalab(&[&round])
+        .success()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[test]
+fn supernova_counts_this_play_too() {
+    assert_eq!(
+        score_with("supernova", "Supernova", "hand_play_counts:\n  Pair: 4\n"),
+        "210"
+    );
+}
+
+#[test]
+fn card_sharp_needs_the_hand_played_earlier_this_round() {
+    assert_eq!(score_with("card_sharp_first", "Card Sharp", ""), "60");
+    assert_eq!(
+        score_with(
+            "card_sharp_again",
+            "Card Sharp",
+            "hand_play_counts:\n  Pair: 1\nround_hand_play_counts:\n  Pair: 1\n"
+        ),
+        "180"
+    );
+}
+
+#[test]
+fn obelisk_resets_once_the_hand_is_the_most_played() {
+    assert_eq!(
+        score_with(
+            "obelisk_reset",
+            "Obelisk",
+            "hand_play_counts:\n  Flush: 3\n  Pair: 3\nhands_since_most_played: 2\n"
+        ),
+        "60"
+    );
+    // A tie with Flush is not the most played, so the streak reaches 3: x1.6 Mult.
+    assert_eq!(
+        score_with(
+            "obelisk_tie",
+            "Obelisk",
+            "hand_play_counts:\n  Flush: 3\n  Pair: 2\nhands_since_most_played: 2\n"
+        ),
+        "96"
+    );
+}
+
+#[test]
+fn blind_carries_play_counts_between_hands() {
+    let blind = input(
+        "play_counts_blind.yml",
+        "target: 1000\ndeck: [K♥, K♠, K♦, K♣]\nhand_size: 2\njokers: [Supernova]\n\
+         actions:\n  - play: [0, 1]\n  - play: [0, 1]\n",
+    );
+    assert_eq!(
+        ortalab(&["blind", &blind]).success(),
+        "Hand 1: Pair [K♦, K♠] +90 (Total: 90 / 1000)\n\
+         Hand 2: Pair [K♥, K♣] +120 (Total: 210 / 1000)\n\
+         Blind failed with 210 after 2 hand(s) and 0 discard(s)\n"
+    );
+}
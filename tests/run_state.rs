@@ -0,0 +1,47 @@
+mod common;
+
+use common::{input, ortalab};
+
+/// Scores a Pair of Kings (30 x 2) with one joker in the given run state.
+fn score_with(name: &str, joker: &str, run: &str) -> String {
+    let round = input(
+        &format!("run_state_{name}.yml"),
+        &format!("cards_played: [K♥, K♠]\njokers: [{joker}]\n{run}"),
+    );
+    ortalab(&[&round])
+        .success()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[test]
+fn jokers_read_hands_and_discards_left() {
+    assert_eq!(score_with("banner", "Banner", "discards_left: 2\n"), "180");
+    assert_eq!(
+        score_with("mystic_summit", "Mystic Summit", "discards_left: 0\n"),
+        "510"
+    );
+    assert_eq!(
+        score_with(
+            "mystic_summit_unused",
+            "Mystic Summit",
+            "discards_left: 1\n"
+        ),
+        "60"
+    );
+    assert_eq!(score_with("acrobat", "Acrobat", "hands_left: 0\n"), "180");
+}
+
+#[test]
+fn jokers_read_money_and_deck_size() {
+    assert_eq!(score_with("bull", "Bull", "money: 11\n"), "104");
+    assert_eq!(score_with("bootstraps", "Bootstraps", "money: 11\n"), "180");
+    // A 52 card deck minus the two played leaves 50 to draw.
+    assert_eq!(score_with("blue_default", "Blue Joker", ""), "260");
+    assert_eq!(
+        score_with("blue_counted", "Blue Joker", "cards_in_deck: 10\n"),
+        "100"
+    );
+}
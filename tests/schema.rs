@@ -0,0 +1,64 @@
+mod common;
+
+use common::{input, ortalab};
+use serde_json::Value;
+
+fn schema() -> Value {
+    serde_json::from_str(ortalab(&["schema"]).success()).expect("schema is JSON")
+}
+
+#[test]
+fn schema_describes_every_round_field() {
+    let schema = schema();
+    let properties: Vec<&str> = schema["properties"]
+        .as_object()
+        .expect("round has properties")
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(
+        properties,
+        [
+            "cards_held_in_hand",
+            "cards_in_deck",
+            "cards_played",
+            "deck",
+            "deck_variant",
+            "discards_left",
+            "hand_play_counts",
+            "hands_left",
+            "hands_since_most_played",
+            "joker_slots",
+            "jokers",
+            "money",
+            "round_hand_play_counts",
+            "seed",
+        ]
+    );
+    assert_eq!(schema["required"], serde_json::json!(["cards_played"]));
+    assert_eq!(
+        schema["$defs"]["DeckVariant"]["enum"][0],
+        Value::from("Plasma Deck")
+    );
+}
+
+#[test]
+fn written_rounds_only_use_schema_fields() {
+    let round = input(
+        "schema_written.yml",
+        "cards_played: [K♥, K♠]\ncards_held_in_hand: [2♣]\njokers: [Joker]\n\
+         hand_play_counts: {Pair: 2}\ndeck_variant: Plasma Deck\n",
+    );
+    let written = ortalab(&["tarot", &round, "--use", "The Empress:0"]);
+    let written: serde_yaml::Value =
+        serde_yaml::from_str(written.success()).expect("written round is YAML");
+
+    let schema = schema();
+    for key in written.as_mapping().expect("round is a mapping").keys() {
+        let key = key.as_str().expect("field names are strings");
+        assert!(
+            schema["properties"].get(key).is_some(),
+            "`{key}` is missing from the schema"
+        );
+    }
+}
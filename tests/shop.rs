@@ -0,0 +1,34 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn liquidation_prices_round_like_the_game() {
+    let shop = input(
+        "shop_liquidation.yml",
+        "money: 20\nseed: 11\nvouchers: [Clearance Sale, Liquidation]\n\
+         jokers: [Joker Rental]\nactions:\n  - sell: 0\n  - open_pack: 1\n    choose: [0]\n",
+    );
+    assert_eq!(
+        ortalab(&["shop", &shop]).success(),
+        "Cards: Splash ($1), Wily Joker ($2)\n\
+         Packs: Celestial Pack ($2), Spectral Pack ($2)\n\
+         Voucher: Reroll Surplus ($5)\n\
+         Sold Joker Rental for $1 ($21 left)\n\
+         Opened Spectral Pack for $2 ($19 left): [Medium, Ouija], took [Medium]\n\
+         Leaving with $19\n\
+         Jokers: []\n\
+         Consumables: [Medium]\n\
+         Vouchers: [Clearance Sale, Liquidation]\n"
+    );
+}
+
+#[test]
+fn jokers_can_roll_negative() {
+    let shop = input("shop_negative.yml", "seed: 152\n");
+    assert!(
+        ortalab(&["shop", &shop])
+            .success()
+            .starts_with("Cards: Pluto ($3), Blue Joker Negative ($10)\n"),
+    );
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn spectral_output_scores_as_a_round() {
+    let round = input(
+        "spectral_grim.yml",
+        "cards_played: [K♥, K♠]\ncards_held_in_hand: [2♣]\nseed: 3\n",
+    );
+    let changed = ortalab(&["spectral", &round, "--use", "Grim"]);
+    assert_eq!(
+        changed.success(),
+        "cards_played:\n- K♠\ncards_held_in_hand:\n- 2♣\n- A♥ Bonus\n- A♦ Mult\n\
+         jokers: []\nmoney: 0\nseed: 3\nhands_left: 3\ndiscards_left: 3\n\
+         hands_since_most_played: 0\njoker_slots: 5\n"
+    );
+
+    let rescored = input("spectral_grim_after.yml", &changed.stdout);
+    assert_eq!(ortalab(&[&rescored]).success(), "15\n");
+}
+
+#[test]
+fn created_cards_are_never_stone() {
+    for seed in 0..50 {
+        let round = input(
+            &format!("spectral_incantation_{seed}.yml"),
+            &format!("cards_played: [K♥]\nseed: {seed}\n"),
+        );
+        let output = ortalab(&["spectral", &round, "--use", "Incantation"]);
+        assert!(
+            !output.success().contains("Stone"),
+            "seed {seed}: {}",
+            output.stdout
+        );
+    }
+}
@@ -0,0 +1,82 @@
+mod common;
+
+use common::{input, ortalab};
+
+/// The chip requirement printed for a blind.
+fn requirement(round: &str, ante: u32, stake: &str, blind: &str) -> String {
+    let output = ortalab(&[
+        round,
+        "--ante",
+        &ante.to_string(),
+        "--stake",
+        stake,
+        "--blind",
+        blind,
+    ]);
+    let line = output.success().lines().nth(1).expect("a blind line");
+    let start = line.find('(').expect("an opening parenthesis") + 1;
+    let end = line.find(')').expect("a closing parenthesis");
+    line[start..end].to_string()
+}
+
+#[test]
+fn small_blind_requirements_follow_the_stake_tables() {
+    let round = input("stake_table.yml", "cards_played: [K♥, K♠]\n");
+    let table: [(&str, [&str; 8]); 3] = [
+        (
+            "white",
+            [
+                "300", "800", "2000", "5000", "11000", "20000", "35000", "50000",
+            ],
+        ),
+        (
+            "green",
+            [
+                "300", "900", "2600", "8000", "20000", "36000", "60000", "100000",
+            ],
+        ),
+        (
+            "purple",
+            [
+                "300", "1000", "3200", "9000", "25000", "60000", "110000", "200000",
+            ],
+        ),
+    ];
+    for (stake, amounts) in table {
+        for (ante, amount) in (1..).zip(amounts) {
+            assert_eq!(
+                requirement(&round, ante, stake, "small"),
+                amount,
+                "ante {ante}, {stake}"
+            );
+        }
+    }
+}
+
+#[test]
+fn big_and_boss_blinds_scale_the_base_amount() {
+    let round = input("stake_blinds.yml", "cards_played: [K♥, K♠]\n");
+    assert_eq!(requirement(&round, 0, "white", "small"), "100");
+    assert_eq!(requirement(&round, 1, "white", "big"), "450");
+    assert_eq!(requirement(&round, 8, "gold", "boss"), "400000");
+}
+
+#[test]
+fn endless_antes_keep_two_significant_digits() {
+    let round = input("stake_endless.yml", "cards_played: [K♥, K♠]\n");
+    assert_eq!(requirement(&round, 9, "white", "small"), "110000");
+    assert_eq!(requirement(&round, 12, "white", "small"), "300000000");
+    assert_eq!(requirement(&round, 12, "purple", "boss"), "2400000000");
+}
+
+#[test]
+fn plasma_deck_doubles_the_requirement() {
+    let round = input(
+        "stake_plasma.yml",
+        "cards_played: [K♥, K♠]\ndeck_variant: Plasma Deck\n",
+    );
+    assert_eq!(
+        ortalab(&[&round, "--ante", "2", "--blind", "boss"]).success(),
+        "256\nAnte 2 Boss Blind, White Stake (3200): Falls short of target 3200 by 2944\n"
+    );
+}
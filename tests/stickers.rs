@@ -0,0 +1,67 @@
+mod common;
+
+use common::{fixture, input, ortalab};
+
+#[test]
+fn perishable_joker_is_debuffed_once_its_last_round_ends() {
+    let last_round = ortalab(&[&fixture("stickers/perishable_last_round.yml")]);
+    assert_eq!(
+        last_round.success(),
+        "180\nJokers after round: Joker Perishable 0 (debuffed)\n"
+    );
+
+    let next_round = ortalab(&[&fixture("stickers/perishable_debuffed.yml"), "--explain"]);
+    assert_eq!(
+        next_round.success(),
+        "Pair (Final Score: 60)\nSkipped debuffed jokers: Joker Perishable 0\n\
+         Jokers after round: Joker Perishable 0 (debuffed)\n"
+    );
+}
+
+#[test]
+fn blind_counts_perishable_jokers_down_at_the_end() {
+    let blind = input(
+        "stickers_blind.yml",
+        "target: 10\ndeck: [K♥, K♠, Q♥, Q♠]\nhand_size: 4\n\
+         jokers: [Joker Perishable 2, Mime]\nactions:\n  - play: [0, 1]\n",
+    );
+    let output = ortalab(&["blind", &blind]);
+    assert!(
+        output
+            .success()
+            .ends_with("Jokers after round: Joker Perishable 1, Mime\n"),
+        "{}",
+        output.stdout
+    );
+}
+
+#[test]
+fn rental_jokers_charge_rent_when_the_round_ends() {
+    let round = input(
+        "stickers_rental.yml",
+        "cards_played: [A♠]\nmoney: 10\njokers: [Joker Rental]\n",
+    );
+    let output = ortalab(&[&round]);
+    assert_eq!(
+        output.success(),
+        "80\nMoney: $10 -> $8 (+$0 during scoring, +$1 at end of round, -$3 rent)\n"
+    );
+}
+
+#[test]
+fn shop_settles_the_round_before_opening() {
+    let shop = input(
+        "stickers_shop.yml",
+        "money: 10\nend_round: true\njokers: [Joker Perishable 1, Golden Joker Rental]\n",
+    );
+    let output = ortalab(&["shop", &shop]);
+    let lines: Vec<&str> = output.success().lines().collect();
+    assert_eq!(
+        lines[..2],
+        [
+            "Round settled. Money: $10 -> $12 (+$0 during scoring, +$5 at end of round, -$3 rent)",
+            "Jokers after round: Joker Perishable 0 (debuffed), Golden Joker Rental",
+        ]
+    );
+    assert!(lines.contains(&"Jokers: [Joker Perishable 0, Golden Joker Rental]"));
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use common::{input, ortalab};
+
+#[test]
+fn target_keeps_every_digit_past_f64_precision() {
+    let round = input("target_pair.yml", "cards_played: [K♥, K♠]\n");
+
+    let output = ortalab(&[&round, "--target", "12345678901234567891"]);
+    assert_eq!(
+        output.success(),
+        "60\nFalls short of target 12345678901234567891 by 12345678901234567831\n"
+    );
+
+    let output = ortalab(&[&round, "--target", "1.2e20"]);
+    assert_eq!(
+        output.success(),
+        "60\nFalls short of target 120000000000000000000 by 119999999999999999940\n"
+    );
+}
+
+#[test]
+fn target_must_be_a_number() {
+    let round = input("target_invalid.yml", "cards_played: [K♥, K♠]\n");
+    let output = ortalab(&[&round, "--target", "1.2e"]);
+    assert_eq!(output.code, Some(2));
+    assert!(
+        output.stderr.contains("Invalid exponent"),
+        "{}",
+        output.stderr
+    );
+}
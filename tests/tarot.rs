@@ -0,0 +1,53 @@
+mod common;
+
+use common::{input, ortalab};
+
+const ROUND: &str = "cards_played: [K♥, K♠]\ncards_held_in_hand: [2♣, 3♦]\n";
+
+#[test]
+fn tarots_apply_in_order() {
+    let round = input("tarot_in_order.yml", ROUND);
+    assert_eq!(
+        ortalab(&[
+            "tarot",
+            &round,
+            "--use",
+            "The Empress:0,1",
+            "--use",
+            "The Hanged Man:3",
+        ])
+        .success(),
+        "cards_played:\n- K♥ Mult\n- K♠ Mult\ncards_held_in_hand:\n- 2♣\n\
+         jokers: []\nmoney: 0\nseed: 0\nhands_left: 3\ndiscards_left: 3\n\
+         hands_since_most_played: 0\njoker_slots: 5\n"
+    );
+}
+
+#[test]
+fn tarot_output_scores_as_a_round() {
+    let round = input("tarot_round_trip.yml", ROUND);
+    let output = format!("{}/tarot_round_trip_after.yml", env!("CARGO_TARGET_TMPDIR"));
+    ortalab(&[
+        "tarot",
+        &round,
+        "--use",
+        "The Empress:0,1",
+        "--output",
+        &output,
+    ])
+    .success();
+
+    // Two Mult Kings add 4 Mult each: 30 x 10.
+    assert_eq!(ortalab(&[&output]).success(), "300\n");
+}
+
+#[test]
+fn tarot_selection_is_checked() {
+    let round = input("tarot_selection.yml", ROUND);
+    let output = ortalab(&["tarot", &round, "--use", "The Empress:0,1,2"]);
+    assert_eq!(output.code, Some(1));
+    assert_eq!(
+        output.stderr,
+        "Error: tarot 1: The Empress needs 1 to 2 cards, got 3\n"
+    );
+}
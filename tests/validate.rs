@@ -0,0 +1,83 @@
+mod common;
+
+use common::{input, ortalab};
+
+/// Validates a round and returns what it printed and the exit status.
+fn validate(name: &str, contents: &str) -> (String, Option<i32>) {
+    let output = ortalab(&["validate", &input(name, contents)]);
+    (output.stdout, output.code)
+}
+
+#[test]
+fn invalid_values_are_located_in_each_format() {
+    assert_eq!(
+        validate(
+            "validate_block.yml",
+            "cards_played:\n  - K♥\n  - X♠\n  - K♠\n"
+        ),
+        (
+            "3:5: error: cards_played: Card `X♠` has invalid rank: Invalid Rank: `X`\n\
+             1 error(s), 0 warning(s)\n"
+                .to_string(),
+            Some(1)
+        )
+    );
+    assert_eq!(
+        validate("validate_flow.yml", "cards_played: [K♥, X♠]\n").0,
+        "1:20: error: cards_played: Card `X♠` has invalid rank: Invalid Rank: `X`\n\
+         1 error(s), 0 warning(s)\n"
+    );
+    assert_eq!(
+        validate(
+            "validate_value.json",
+            "{\n  \"cards_played\": [\"K♥\", \"Z♠\"]\n}\n"
+        )
+        .0,
+        "2:27: error: Card `Z♠` has invalid rank: Invalid Rank: `Z`\n\
+         1 error(s), 0 warning(s)\n"
+    );
+    assert_eq!(
+        validate("validate_value.toml", "cards_played = [\"K♥\", \"Z♠\"]\n").0,
+        "1:24: error: Card `Z♠` has invalid rank: Invalid Rank: `Z`\n\
+         1 error(s), 0 warning(s)\n"
+    );
+}
+
+#[test]
+fn impossible_rounds_point_at_the_offending_item() {
+    assert_eq!(
+        validate(
+            "validate_six_cards.yml",
+            "cards_played: [K♥, K♠, Q♠, J♠, 10♠, 9♠]\n"
+        ),
+        (
+            "1:37: error: 6 cards played, but a hand plays at most 5\n\
+             1 error(s), 0 warning(s)\n"
+                .to_string(),
+            Some(1)
+        )
+    );
+    assert_eq!(
+        validate(
+            "validate_jokers.yml",
+            "cards_played: [K♥, K♠]\n\
+             jokers:\n  - Joker\n  - Joker\n  - Joker\n  - Joker\n  - Joker\n  - Joker\n"
+        )
+        .0,
+        "8:5: error: 6 non-Negative jokers do not fit in 5 joker slots\n\
+         1 error(s), 0 warning(s)\n"
+    );
+}
+
+#[test]
+fn unusual_rounds_only_warn() {
+    assert_eq!(
+        validate("validate_copies.toml", "cards_played = [\"K♥\", \"K♥\"]\n"),
+        (
+            "1:23: warning: more copies of K♥ in play than the 1 in the deck\n\
+             0 error(s), 1 warning(s)\n"
+                .to_string(),
+            Some(0)
+        )
+    );
+}